@@ -0,0 +1,20 @@
+#pragma once
+
+#include "Hotkeys.hpp"
+
+// What each of BOARD_AUX_PINS does, in the same order. A button with an
+// action runs it when pressed, one without holds its key down for as long
+// as the button is.
+//
+// A different table can be used with -D AUX_CONFIG='"MyAuxButtons.h"'.
+typedef struct AuxButton {
+    EHotkeyAction action;
+    KeyboardKeycode key;
+} AuxButton;
+
+const AuxButton auxButtons[] = {
+    // panic button, stops all output until pressed again
+    {HOTKEY_KILL_SWITCH, KEY_RESERVED},
+    // screenshot
+    {HOTKEY_NONE, KEY_PRINT},
+};
@@ -0,0 +1,65 @@
+#pragma once
+
+#include "KeyboardConfig.h"
+#include "KeyboardInterface.hpp"
+
+// the well, a row fits in the low bits of a short
+#define BLOCKS_WIDTH 10
+#define BLOCKS_HEIGHT 16
+#define BLOCKS_CELL 2
+#define BLOCKS_FULL_ROW ((1 << BLOCKS_WIDTH) - 1)
+
+// time between steps down, shorter by BLOCKS_SPEEDUP_MS every ten lines
+#define BLOCKS_START_MS 600
+#define BLOCKS_SPEEDUP_MS 50
+#define BLOCKS_FASTEST_MS 100
+
+enum EBlocksAction : unsigned char {
+    BLOCKS_NONE, BLOCKS_LEFT, BLOCKS_RIGHT, BLOCKS_ROTATE, BLOCKS_DROP, BLOCKS_RESTART
+};
+
+// Falling blocks, 4 and 6 move, 5 rotates and 2 drops the piece. Pieces are
+// 4x4 bit masks with the top left cell in the highest bit.
+class Blocks : public KeyboardInterface {
+    protected:
+        const unsigned char mapping[ROWS][COLS] = {
+            {BLOCKS_NONE,    BLOCKS_NONE,   BLOCKS_NONE,  BLOCKS_NONE},
+            {BLOCKS_RESTART, BLOCKS_NONE,   BLOCKS_NONE,  BLOCKS_NONE},
+            {BLOCKS_NONE,    BLOCKS_ROTATE, BLOCKS_NONE,  BLOCKS_NONE},
+            {BLOCKS_LEFT,    BLOCKS_ROTATE, BLOCKS_RIGHT, BLOCKS_NONE},
+            {BLOCKS_NONE,    BLOCKS_DROP,   BLOCKS_NONE,  BLOCKS_NONE},
+            {BLOCKS_NONE,    BLOCKS_NONE,   BLOCKS_NONE,  BLOCKS_NONE},
+        };
+
+        unsigned short well[BLOCKS_HEIGHT];
+
+        unsigned short piece;
+        unsigned char pieceType;
+        unsigned char nextType;
+        signed char x;
+        signed char y;
+
+        unsigned long score;
+        unsigned short lines;
+        bool over;
+
+        unsigned long lastStep = 0;
+        unsigned long seed = 0;
+
+        unsigned char getAction(unsigned char row, unsigned char column) const;
+        bool fits(const unsigned short shape, const signed char x, const signed char y) const;
+        void spawn();
+        void lock();
+        bool step();
+        void drawShape(U8G2* u8g2, const unsigned short shape, const unsigned char left, const signed char top);
+
+    public:
+        void onPress(char row, char column) override;
+        void draw(U8G2* u8g2) override;
+        void tick(const unsigned long ms) override;
+        void onShow() override;
+        bool allowsTapChords() const override { return false; }
+        bool describeKey(char row, char column, char* label) const override;
+
+        void reset();
+};
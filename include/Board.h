@@ -0,0 +1,38 @@
+#pragma once
+
+#include "Arduino.h"
+
+// Pin assignments for each supported PCB. Pick one with a build flag such as
+// -D BOARD_NUMCAL, the original NumCal PCB is used when none is given.
+
+#if !defined(BOARD_NUMCAL)
+#define BOARD_NUMCAL
+#endif
+
+#if defined(BOARD_NUMCAL)
+    #define BOARD_NAME "NumCal"
+
+    // key matrix, rows are driven low one at a time and the columns read back
+    #define BOARD_COL_PINS {A3, A2, A1, A0}
+    #define BOARD_ROW_PINS {9, 8, 7, 6, 5, 4}
+
+    // SSD1305 on software SPI
+    #define PIN_DISPLAY_CLOCK 15
+    #define PIN_DISPLAY_DATA 16
+    #define PIN_DISPLAY_CS 10
+    #define PIN_DISPLAY_DC 14
+    #define PIN_DISPLAY_RESET 3
+
+    // buttons wired from a pin straight to ground, outside the matrix. Pins
+    // 0 to 2 are free, their bindings are in AuxConfig.h
+    // #define BOARD_AUX_PINS {2}
+    // #define BOARD_AUX_COUNT 1
+#endif
+
+#ifndef BOARD_AUX_COUNT
+#define BOARD_AUX_COUNT 0
+#endif
+
+#ifndef BOARD_NAME
+#error "No pin assignments for the selected board"
+#endif
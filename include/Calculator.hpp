@@ -2,12 +2,76 @@
 
 #define _C(x) (const unsigned char)(x)
 
+#include "EepromLayout.h"
 #include "KeyboardConfig.h"
 #include "KeyboardInterface.hpp"
+#include "HeldKeys.hpp"
 
 #define CALC_VALUE_SIZE 16
 #define CALC_PRECISION 4
-#define ADDR_EEPROM_CALC_MEMORY 0
+
+// "<value> <op> <value> =" with the terminator
+#define CALC_EXPRESSION_SIZE (CALC_VALUE_SIZE * 2 + 6)
+
+// most decimals a result can be set to show
+#define CALC_MAX_PRECISION 6
+
+// how results are cut down to the decimals shown
+enum ECalcRounding : unsigned char {
+    CALC_ROUND_HALF_UP,   // away from zero, 2.5 to 3
+    CALC_ROUND_HALF_EVEN, // banker's, 2.5 to 2 and 3.5 to 4
+    CALC_ROUND_TRUNCATE,  // towards zero, 2.9 to 2
+    CALC_ROUND_COUNT,
+};
+
+// percent added by tax plus and taken off by tax minus, until one is set
+// with the calc tax console command
+#ifndef CALC_TAX_RATE
+#define CALC_TAX_RATE 20
+#endif
+
+// results that are a fraction with at most this below the line show as one
+// while fractions are on
+#define CALC_MAX_DENOMINATOR 100
+
+// a result with room for a separator every three digits
+#define CALC_SHOWN_SIZE (CALC_VALUE_SIZE + CALC_VALUE_SIZE / 3 + 1)
+
+// long pressing the point or enter types the result with keypad keys, 0 to
+// go through the keyboard layout instead
+#ifndef CALC_TYPE_KEYPAD
+#define CALC_TYPE_KEYPAD 1
+#endif
+
+// characters a result shows with before it goes to exponent notation, at
+// most CALC_VALUE_SIZE
+#ifndef CALC_MAX_WIDTH
+#define CALC_MAX_WIDTH CALC_VALUE_SIZE
+#endif
+
+// completed calculations kept for looking back at
+#ifndef CALC_HISTORY_SIZE
+#define CALC_HISTORY_SIZE 4
+#endif
+
+// what undo puts back, memory slots in EEPROM and the history stay as they are
+typedef struct CalcSnapshot {
+    char input[CALC_VALUE_SIZE + 1];
+    double result;
+    char pendingOperation;
+    bool staleInput;
+    bool error;
+    bool afterEquals;
+    double repeatOperand;
+    double grandTotal;
+} CalcSnapshot;
+
+typedef struct CalcHistoryEntry {
+    double left;
+    char operation;
+    double right;
+    double result;
+} CalcHistoryEntry;
 
 class Calculator : public KeyboardInterface {
     protected:
@@ -22,20 +86,52 @@ class Calculator : public KeyboardInterface {
             {'0', '0', '.', '\n'},
         };
 
-        bool drawNext = true;
-
         char input[CALC_VALUE_SIZE + 1];
         double result;
         char resultBuffer[CALC_VALUE_SIZE + 1];
         
         char pendingOperation = 0;
+
+        // the last result came from equals, which pressed again applies
+        // pendingOperation with repeatOperand once more
+        bool afterEquals = false;
+        double repeatOperand = 0;
         char staleInput;
-        bool error;
+        bool error = false;
+
+        // sum of every result of enter, shown by long pressing plus
+        double grandTotal = 0;
+        bool grandTotalShown = false;
+
+        // keys with a long press function act on release instead, unless
+        // the long press happened or another key went with them, e.g. a
+        // memory key with plus
+        HeldKeys heldKeys;
+
+        bool hasLongPress(const char input) const;
+        void deliver();
+
+        // applied to the shown result, the result itself keeps every digit
+        unsigned char precision = CALC_PRECISION;
+        ECalcRounding rounding = CALC_ROUND_HALF_UP;
+        double taxRate = CALC_TAX_RATE;
+        bool fractions = false;
+        char separator = 0;
+
+        void saveSettings() const;
+
+        // trigonometry works in degrees unless switched with long press 0
+        bool radians = false;
+
+        void doFunction(const char function);
+        void negate();
         
         void doOperation(char op);
         void doNumeric(const char input);
         void doMath(char op);
         bool hasPoint() const; 
+        bool hasExponent() const;
+        bool hasMemory() const;
 
         bool pushInput(const char value);
         bool pushResult(const char value);
@@ -43,21 +139,67 @@ class Calculator : public KeyboardInterface {
         void updateResultBuffer();
 
         bool push(char* target, const char value, const unsigned char size = CALC_VALUE_SIZE);
+
+        // the state before the last key, one step deep
+        CalcSnapshot snapshot;
+        bool canUndo = false;
+
+        void saveUndo();
+        void undo();
+
+        // ring buffer, newest at historyNext - 1
+        CalcHistoryEntry history[CALC_HISTORY_SIZE];
+        unsigned char historyNext = 0;
+        unsigned char historyCount = 0;
+
+        void addHistory(const double left, const char operation, const double right);
     public:
         Calculator();
 
         void onPress(const char row, const char column) override;
         void onPress(const char input);
+        // A short press of the key straight away, without waiting for a long
+        // press, for mirroring keys that already went to the host
+        void tap(const char row, const char column);
+        void onRelease(const char row, const char column) override;
         void onLongPress(const char row, const char column) override;
         void onLongPress(const char input);
         void draw(U8G2* u8g2) override;
         void onShow() override;
+        bool describeKey(char row, char column, char* label) const override;
+        bool allowsTapChords() const override { return false; }
 
         void loadMemory(const unsigned char slot);
         void storeMemory(const unsigned char slot, double data) const; 
+        void updateMemory(const unsigned char slot, const char op);
+        double readMemory(const unsigned char slot) const;
+
+        // Persisted in EEPROM, loaded again when the mode is shown
+        void setPrecision(const unsigned char precision);
+        void setRounding(const ECalcRounding rounding);
+        unsigned char getPrecision() const;
+        ECalcRounding getRounding() const;
+        void setTaxRate(const double percent);
+        double getTaxRate() const;
+        void setFractions(const bool fractions);
+        bool getFractions() const;
+        // groups the digits of the shown result, 0 for none
+        void setSeparator(const char separator);
+        char getSeparator() const;
+        void loadSettings();
 
         void clearInput();
         void clearResult();
         const char* getInput() const;
         const char* getResult() const;
+        // Latched by e.g. dividing by zero, only clear gets out of it
+        bool hasError() const;
+
+        // What's pending, e.g. "12.5 +", or "12.5 + 7.3 =" after equals. Not
+        // full only ever gives the short form. Needs CALC_EXPRESSION_SIZE.
+        char* getExpression(char* buffer, const unsigned char size, const bool full = true) const;
+
+        // back 0 is the latest calculation, nullptr past the oldest kept
+        const CalcHistoryEntry* getHistory(const unsigned char back) const;
+        unsigned char getHistoryCount() const;
 };
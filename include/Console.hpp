@@ -0,0 +1,22 @@
+#pragma once
+
+#include "Arduino.h"
+
+#define CONSOLE_LINE_SIZE 40
+
+// Line based command interface on the CDC serial port
+class Console {
+    private:
+        char line[CONSOLE_LINE_SIZE + 1];
+        unsigned char length = 0;
+
+    protected:
+        void run(char* command);
+        void editMacro(char* args);
+        void calcSettings(char* args);
+        void injectKey(char* args);
+
+    public:
+        // Reads whatever input is waiting and runs completed lines
+        void process();
+};
@@ -0,0 +1,50 @@
+#pragma once
+
+// Unit conversion for a converter mode. Each unit is a factor to the base
+// unit of its category, plus an offset for the ones that don't start at the
+// same zero, like temperatures.
+
+enum EUnitCategory : unsigned char {
+    UNIT_LENGTH,
+    UNIT_MASS,
+    UNIT_TEMPERATURE,
+    UNIT_DATA,
+    UNIT_CATEGORY_COUNT,
+};
+
+enum EUnit : unsigned char {
+    UNIT_MM,
+    UNIT_CM,
+    UNIT_M,
+    UNIT_KM,
+    UNIT_IN,
+    UNIT_FT,
+    UNIT_YD,
+    UNIT_MI,
+    UNIT_G,
+    UNIT_KG,
+    UNIT_OZ,
+    UNIT_LB,
+    UNIT_CELSIUS,
+    UNIT_FAHRENHEIT,
+    UNIT_KELVIN,
+    UNIT_BYTE,
+    UNIT_KB,
+    UNIT_MB,
+    UNIT_GB,
+    UNIT_KIB,
+    UNIT_MIB,
+    UNIT_GIB,
+    UNIT_COUNT,
+};
+
+// longest unit name with the terminator
+#define UNIT_NAME_SIZE 4
+
+// Converts value, false when the units measure different things
+bool conversion_convert(const double value, const EUnit from, const EUnit to, double* result);
+
+EUnitCategory conversion_category(const EUnit unit);
+
+// Copies the short name, e.g. "km", into name of UNIT_NAME_SIZE
+char* conversion_name(char* name, const EUnit unit);
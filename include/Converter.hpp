@@ -0,0 +1,37 @@
+#pragma once
+
+#include "KeyboardConfig.h"
+#include "KeyboardInterface.hpp"
+#include "Calculator.hpp"
+#include "Conversion.hpp"
+
+// Converts the number typed in between two units, both shown at once. The
+// top row picks length, mass, temperature or data, divide and multiply
+// step through the units on either side and plus swaps them.
+class Converter : public KeyboardInterface {
+    protected:
+        const unsigned char mapping[ROWS][COLS] = {
+            {'l', 'm', 't', 'd'},
+            {'C', 'f', 'o', '-'},
+            {'7', '8', '9', 's'},
+            {'4', '5', '6', 's'},
+            {'1', '2', '3', '\n'},
+            {'0', '0', '.', '\n'},
+        };
+
+        char input[CALC_VALUE_SIZE + 1] = "";
+
+        EUnit from = UNIT_M;
+        EUnit to = UNIT_FT;
+
+        unsigned char getChar(unsigned char row, unsigned char column) const;
+        void pick(const EUnitCategory category);
+        bool convert(double* result) const;
+
+    public:
+        void onPress(char row, char column) override;
+        void draw(U8G2* u8g2) override;
+        void onShow() override;
+        bool describeKey(char row, char column, char* label) const override;
+        bool allowsTapChords() const override { return false; }
+};
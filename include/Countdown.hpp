@@ -0,0 +1,47 @@
+#pragma once
+
+#include "KeyboardConfig.h"
+#include "KeyboardInterface.hpp"
+
+// how fast the screen flashes once the time is up
+#define COUNTDOWN_FLASH_MS 250
+
+// Counts down from the minutes and seconds typed in, e.g. 130 for 1:30.
+// Enter starts and pauses. It keeps going while another mode is shown and
+// takes the screen back when it runs out, flashing until a key is pressed.
+class Countdown : public KeyboardInterface {
+    protected:
+        const unsigned char mapping[ROWS][COLS] = {
+            {0, 0, 0, 0},
+            {'C', 0, 0, 0},
+            {'7', '8', '9', '\n'},
+            {'4', '5', '6', '\n'},
+            {'1', '2', '3', '\n'},
+            {'0', '0', 0, '\n'},
+        };
+
+        // minutes and seconds as typed, the last two digits are seconds
+        unsigned int entered = 0;
+
+        bool running = false;
+        bool ringing = false;
+
+        // while running it ends at deadline, otherwise remaining is left
+        unsigned long deadline = 0;
+        unsigned long remaining = 0;
+
+        unsigned long shownStep = 0;
+
+        unsigned char getChar(unsigned char row, unsigned char column) const;
+        unsigned long left(const unsigned long ms) const;
+
+    public:
+        void onPress(char row, char column) override;
+        void draw(U8G2* u8g2) override;
+        void tick(const unsigned long ms) override;
+        void onShow() override;
+        bool describeKey(char row, char column, char* label) const override;
+        bool allowsTapChords() const override { return false; }
+
+        bool isRinging() const { return this->ringing; }
+};
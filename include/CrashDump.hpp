@@ -0,0 +1,21 @@
+#pragma once
+
+#include "Arduino.h"
+
+#define CRASH_DUMP_MAGIC 0xC4
+
+typedef struct CrashRecord {
+    unsigned char magic;
+    unsigned short pc;
+    unsigned short sp;
+    unsigned long uptime;
+    unsigned long time; // wall clock, 0 if the host never set it
+} CrashRecord;
+
+// Makes the watchdog raise an interrupt before it resets the board, which
+// is used to save where the firmware was stuck. Call after wdt_enable.
+void crashdump_begin();
+
+bool crashdump_load(CrashRecord* record);
+void crashdump_clear();
+void crashdump_print(Print* out);
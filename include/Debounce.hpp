@@ -0,0 +1,45 @@
+#pragma once
+
+#include "KeyboardConfig.h"
+#include "Board.h"
+
+// How raw switch readings become key presses
+#define DEBOUNCE_EAGER 0      // report the first edge, then ignore the switch for a while
+#define DEBOUNCE_DEFERRED 1   // report once the reading held still for a while
+#define DEBOUNCE_INTEGRATOR 2 // count readings up and down, report at either end
+
+#ifndef DEBOUNCE_ALGORITHM
+#define DEBOUNCE_ALGORITHM DEBOUNCE_DEFERRED
+#endif
+
+// milliseconds for eager and deferred, at most 255
+#ifndef DEBOUNCE_MS
+#define DEBOUNCE_MS 5
+#endif
+
+// readings in a row for the integrator
+#ifndef DEBOUNCE_SAMPLES
+#define DEBOUNCE_SAMPLES 4
+#endif
+
+// Filters contact bounce out of the matrix scan, one instance covers every key.
+// Auxiliary buttons come after the matrix keys.
+class Debounce {
+    private:
+        typedef struct KeyState {
+            bool pressed;
+            bool raw;
+            // low byte of millis() of the last edge, or the integrator count
+            unsigned char since;
+        } KeyState;
+
+        KeyState keys[ROWS * COLS + BOARD_AUX_COUNT] = {};
+
+    public:
+        // Takes the raw reading of a key and returns whether it counts as
+        // pressed
+        bool update(const unsigned char key, const bool raw, const unsigned long time);
+
+        // Treats a key as down from the start, e.g. one held at power-up
+        void hold(const unsigned char key);
+};
@@ -0,0 +1,14 @@
+#pragma once
+
+#include "KeyboardInterface.hpp"
+
+// Shows how much RAM is left, refreshed once a second
+class Diagnostics : public KeyboardInterface {
+    protected:
+        unsigned long lastUpdate = 0;
+
+    public:
+        void draw(U8G2* u8g2) override;
+        void tick(const unsigned long ms) override;
+        void onShow() override;
+};
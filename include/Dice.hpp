@@ -0,0 +1,47 @@
+#pragma once
+
+#include "KeyboardConfig.h"
+#include "KeyboardInterface.hpp"
+
+// how long the faces spin before the roll settles
+#define DICE_ROLL_MS 600
+#define DICE_FRAME_MS 60
+
+// Rolls a die with as many sides as the key says: 4, 6 and 8, 0 for a d10,
+// 2 for a d12 and 2 held for a d20. The board has no hardware random source,
+// so the generator is reseeded from the microsecond each key comes in at.
+class Dice : public KeyboardInterface {
+    protected:
+        const unsigned char mapping[ROWS][COLS] = {
+            {0, 0, 0, 0},
+            {0, 0, 0, 0},
+            {0, 8, 0, 0},
+            {4, 0, 6, 0},
+            {0, 12, 0, 0},
+            {10, 10, 0, 0},
+        };
+
+        unsigned long seed = 0;
+        unsigned char sides = 0;
+        unsigned char result = 0;
+        unsigned char shownFace = 0;
+
+        bool rolling = false;
+        unsigned long rollEnds = 0;
+        unsigned long lastFrame = 0;
+
+        // the d12 key rolls on release, unless it was held for a d20
+        bool longPressed = false;
+
+        unsigned char getSides(unsigned char row, unsigned char column) const;
+        void roll(const unsigned char sides);
+
+    public:
+        void onPress(char row, char column) override;
+        void onRelease(char row, char column) override;
+        void onLongPress(char row, char column) override;
+        void draw(U8G2* u8g2) override;
+        void tick(const unsigned long ms) override;
+        void onShow() override;
+        bool describeKey(char row, char column, char* label) const override;
+};
@@ -44,6 +44,7 @@ class DinoGame : public KeyboardInterface {
         void draw(U8G2* u8g2) override;
         void tick(const unsigned long ms) override;
         void onShow() override;
+        bool allowsTapChords() const override { return false; }
         void spawnMob();
 
         void reset();
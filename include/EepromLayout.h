@@ -0,0 +1,12 @@
+#pragma once
+
+// Everything that persists lives in EEPROM, keep the offsets in one place so
+// they can't overlap.
+
+#define ADDR_EEPROM_CALC_MEMORY 0 // 4 memory slots of sizeof(double)
+#define ADDR_EEPROM_CRASH_DUMP 32 // CrashRecord
+#define ADDR_EEPROM_MACROS 64 // MACRO_SLOTS of a length byte and MACRO_SIZE steps
+#define ADDR_EEPROM_LAST_MODE 512 // index into the mode rotation, restored at boot
+#define ADDR_EEPROM_PIN 516 // hash of the lock screen PIN, all ones when unset
+#define ADDR_EEPROM_CALC_SETTINGS 520 // see the CALC_SETTING_ offsets in Calculator.cpp
+#define ADDR_EEPROM_PIN_FAILURES 532 // wrong PINs in a row and lockouts so far, a byte each
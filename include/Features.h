@@ -0,0 +1,154 @@
+#pragma once
+
+// Subsystems that can be compiled out of flash or RAM constrained builds.
+// Override from platformio.ini, e.g. build_flags = -D FEATURE_GAMES=1
+
+// calculator mode
+#ifndef FEATURE_CALCULATOR
+#define FEATURE_CALCULATOR 1
+#endif
+
+// reverse polish calculator, next to the regular one in the rotation
+#ifndef FEATURE_RPN
+#define FEATURE_RPN 0
+#endif
+
+// 64 bit integer calculator with hex, binary and octal and bitwise operators
+#ifndef FEATURE_PROGRAMMER
+#define FEATURE_PROGRAMMER 0
+#endif
+
+// numpad mode that also shows the calculator result, needs the calculator
+#ifndef FEATURE_LIVE_NUMPAD
+#define FEATURE_LIVE_NUMPAD FEATURE_CALCULATOR
+#endif
+
+// boot screen with the firmware version
+#ifndef FEATURE_SPLASH
+#define FEATURE_SPLASH 1
+#endif
+
+// lock screen at boot once a PIN is set with the "pin" console command
+#ifndef FEATURE_PIN_LOCK
+#define FEATURE_PIN_LOCK 1
+#endif
+
+// count, sum, mean and standard deviation of typed numbers
+#ifndef FEATURE_STATISTICS
+#define FEATURE_STATISTICS 0
+#endif
+
+// numpad showing keys and words per minute over the last minute
+#ifndef FEATURE_TYPING_METER
+#define FEATURE_TYPING_METER 0
+#endif
+
+// enter types an incrementing number, see Sequence.hpp for the defaults
+#ifndef FEATURE_SEQUENCE
+#define FEATURE_SEQUENCE 0
+#endif
+
+// 25 minute work and 5 minute break intervals with a progress bar
+#ifndef FEATURE_POMODORO
+#define FEATURE_POMODORO 0
+#endif
+
+// time of day set from the console, in a large font
+#ifndef FEATURE_CLOCK
+#define FEATURE_CLOCK 0
+#endif
+
+// countdown timer that keeps going in the background and flashes when done
+#ifndef FEATURE_COUNTDOWN
+#define FEATURE_COUNTDOWN 0
+#endif
+
+// dice from d4 to d20
+#ifndef FEATURE_DICE
+#define FEATURE_DICE 0
+#endif
+
+// falling blocks game in the mode rotation
+#ifndef FEATURE_BLOCKS
+#define FEATURE_BLOCKS 0
+#endif
+
+// nudges the mouse pointer now and then so the host doesn't go idle
+#ifndef FEATURE_JIGGLER
+#define FEATURE_JIGGLER 0
+#endif
+
+// volume and playback keys
+#ifndef FEATURE_MEDIA
+#define FEATURE_MEDIA 0
+#endif
+
+// the keypad as a mouse, the digits around 5 move the pointer
+#ifndef FEATURE_MOUSE
+#define FEATURE_MOUSE 0
+#endif
+
+// length, mass, temperature and data units, see Conversion.hpp
+#ifndef FEATURE_CONVERTER
+#define FEATURE_CONVERTER 0
+#endif
+
+// number keys type the phrases in SnippetConfig.h
+#ifndef FEATURE_SNIPPETS
+#define FEATURE_SNIPPETS 0
+#endif
+
+// USB serial to UART adapter on pins 0 and 1
+#ifndef FEATURE_UART_BRIDGE
+#define FEATURE_UART_BRIDGE 0
+#endif
+
+// dino game, reached by holding scroll lock while long pressing num lock
+#ifndef FEATURE_GAMES
+#define FEATURE_GAMES 0
+#endif
+
+// RAM usage screen in the mode rotation
+#ifndef FEATURE_DIAGNOSTICS
+#define FEATURE_DIAGNOSTICS 0
+#endif
+
+// serial command console
+#ifndef FEATURE_CONSOLE
+#define FEATURE_CONSOLE 1
+#endif
+
+// timing of the main loop stages, printed by the "perf" console command
+#ifndef FEATURE_PROFILING
+#define FEATURE_PROFILING 0
+#endif
+
+// raw and debounced key transitions, printed by the "trace" console command
+#ifndef FEATURE_TRACE
+#define FEATURE_TRACE 0
+#endif
+
+// "key", "screen" and "hid" console commands for driving the keypad from a
+// host side test runner
+#ifndef FEATURE_TEST_HOOKS
+#define FEATURE_TEST_HOOKS 0
+#endif
+
+// qr_draw for showing text as a QR code, needs the ricmoo/QRCode lib_dep
+// in platformio.ini
+#ifndef FEATURE_QR
+#define FEATURE_QR 0
+#endif
+
+// key event logging over the CDC serial port
+#ifndef FEATURE_DEBUG_LOG
+#define FEATURE_DEBUG_LOG 1
+#endif
+
+#if FEATURE_LIVE_NUMPAD && !FEATURE_CALCULATOR
+#error "FEATURE_LIVE_NUMPAD needs FEATURE_CALCULATOR"
+#endif
+
+#if FEATURE_DEBUG_LOG
+#define DEBUG
+#endif
@@ -0,0 +1,31 @@
+#pragma once
+
+// Formatting helpers that write into caller owned buffers instead of going
+// through String, which would allocate on the heap.
+
+// Right aligns value in a field of width characters, padded with pad
+char* format_int(char* buffer, const unsigned char size, long value, const unsigned char width = 0, const char pad = ' ');
+
+// Formats value with at most precision decimals, trailing zeros trimmed.
+// Values too long for the buffer switch to exponent notation.
+char* format_double(char* buffer, const unsigned char size, double value, const unsigned char precision);
+
+// Formats value as e.g. 1.2345e+12, with as many decimals as fit
+char* format_exponent(char* buffer, const unsigned char size, double value);
+
+// Formats value as a fraction like "-5/3", false when it's a whole number or
+// no fraction with at most maxDenominator below the line comes close enough
+bool format_fraction(char* buffer, const unsigned char size, double value, const long maxDenominator);
+
+// Formats value followed by a unit suffix, e.g. "12ms"
+char* format_unit(char* buffer, const unsigned char size, long value, const char* suffix);
+
+// Formats a number of seconds as minutes and seconds, e.g. "25:00"
+char* format_clock(char* buffer, const unsigned char size, unsigned long seconds);
+
+// Puts separator between every three digits before the point of the number
+// already in buffer, e.g. 1234567.5 to 1,234,567.5
+char* format_group(char* buffer, const unsigned char size, const char separator);
+
+// Right aligns the string already in buffer in a field of width characters
+char* pad_left(char* buffer, const unsigned char size, const unsigned char width, const char pad = ' ');
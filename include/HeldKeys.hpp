@@ -0,0 +1,50 @@
+#pragma once
+
+#include "Hotkeys.hpp"
+
+// keys that can be waiting at once, past that the oldest is dropped
+#define HELD_KEYS_SIZE 6
+
+typedef struct HeldKey {
+    unsigned char key; // KEY_ID
+    char input;
+    bool down;
+    // long pressed or part of a chord, nothing left to deliver
+    bool done;
+} HeldKey;
+
+// Keys that do one thing tapped and another held have to wait for their
+// release. Taps come back out of next() in the order the keys went down,
+// whatever order they're let go in, so rolling from one key onto the next
+// neither drops nor reorders them.
+class HeldKeys {
+    private:
+        HeldKey keys[HELD_KEYS_SIZE];
+        unsigned char count = 0;
+
+        HeldKey* find(const unsigned char key);
+        void remove(const unsigned char index);
+
+    public:
+        // Queues a key that went down, one that doesn't wait is a tap as
+        // soon as the keys before it are out
+        void press(const unsigned char key, const char input, const bool waits);
+
+        // False when the key wasn't waiting
+        bool release(const unsigned char key);
+
+        // False when the key wasn't waiting or its long press already
+        // happened, so holding on doesn't repeat it
+        bool longPress(const unsigned char key);
+
+        // The latest key still down, which a chord goes with, 0 for none
+        char held() const;
+
+        // The held key went into a chord, it won't be a tap anymore
+        void chord();
+
+        // Next tap in press order, 0 while the oldest key is undecided
+        char next();
+
+        void clear();
+};
@@ -0,0 +1,89 @@
+#pragma once
+
+#include "HID-Project.h"
+#include "Features.h"
+
+// the mouse and consumer reports only go into the USB descriptor for modes
+// that use them
+#define HID_MOUSE (FEATURE_JIGGLER || FEATURE_MOUSE)
+#define HID_CONSUMER FEATURE_MEDIA
+
+// least time between two typed characters, a character is a press and a
+// release report
+#ifndef HID_TYPE_INTERVAL
+#define HID_TYPE_INTERVAL 8
+#endif
+
+// characters waiting to be typed, anything past that is dropped
+#define HID_TYPE_QUEUE_SIZE 32
+
+// reasons for holding back output, each one has to be lifted on its own
+enum EHidBlock : unsigned char {
+    HID_BLOCK_LOCK = 1, // PIN lock screen
+    HID_BLOCK_KILL = 2, // kill switch chord
+};
+
+// Every keyboard report the firmware sends goes through here, so features
+// like macro recording see the same keys as the host. Synthetic output, e.g.
+// macro playback, is marked so it doesn't get recorded again.
+class HidOutput {
+    private:
+        unsigned char blocks = 0;
+
+        // typed characters, keypad keys have the top bit set
+        char queue[HID_TYPE_QUEUE_SIZE];
+        unsigned char head = 0;
+        unsigned char count = 0;
+        unsigned long lastType = 0;
+
+        void queueCharacter(const char character);
+        void type(const char character);
+
+    public:
+        // While blocked nothing reaches the host or the macro recorder,
+        // blocking releases whatever is held first
+        void block(const EHidBlock reason);
+        void unblock(const EHidBlock reason);
+        bool isBlocked(const EHidBlock reason) const;
+        bool isEnabled() const;
+
+        void press(const KeyboardKeycode key, const bool synthetic = false);
+        void release(const KeyboardKeycode key, const bool synthetic = false);
+        void releaseAll();
+
+        // Types a character through the keyboard layout, never recorded.
+        // Typing is queued and paced by tick, so a long text can't flood
+        // the host or stall the scan loop.
+        void write(const char character);
+        void print(const char* text);
+
+        // Types a number with keypad keys, which unlike the layout's digits
+        // come out the same on every host layout as long as its num lock is
+        // on. Anything the keypad doesn't have, like the e of an exponent,
+        // goes through the layout. Never recorded.
+        void printKeypad(const char* number);
+
+        // True while queued characters are still being typed
+        bool isTyping() const;
+        // Drops whatever hasn't been typed yet
+        void stopTyping();
+
+        // Types the next queued character when its time has come
+        void tick(const unsigned long ms);
+
+        // System control report, e.g. SYSTEM_SLEEP, never recorded
+        void system(const SystemKeycode key);
+
+        // Consumer control report, e.g. MEDIA_VOLUME_UP, never recorded.
+        // Does nothing unless a mode that needs it is built in.
+        void consumerPress(const ConsumerKeycode key);
+        void consumerRelease(const ConsumerKeycode key);
+
+        // Relative pointer movement, never recorded. Does nothing unless a
+        // mode that needs the mouse report is built in.
+        void mouseMove(const signed char x, const signed char y, const signed char wheel = 0);
+        void mousePress(const uint8_t buttons);
+        void mouseRelease(const uint8_t buttons);
+};
+
+extern HidOutput hid;
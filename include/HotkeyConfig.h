@@ -0,0 +1,48 @@
+#pragma once
+
+#include "Hotkeys.hpp"
+#include <avr/pgmspace.h>
+
+// Chords and the action they trigger once every key in them has been held
+// for holdMs. The first chord whose keys are all down wins, so list longer
+// chords before the shorter ones they contain.
+//
+// A different table can be used with -D HOTKEY_CONFIG='"MyHotkeys.h"'.
+const Hotkey hotkeyTable[] PROGMEM = {
+    // scroll lock + num lock
+    {{KEY_ID(0, 0), KEY_ID(1, 0), NO_KEY}, 1000, HOTKEY_GAME_MODE},
+    // num lock + delete, held so clear and a memory key overlapping in the
+    // calculator doesn't lock the screen
+    {{KEY_ID(1, 0), KEY_ID(0, 2), NO_KEY}, 1000, HOTKEY_LOCK_HOST},
+    // num lock + minus, puts the host to sleep
+    {{KEY_ID(1, 0), KEY_ID(1, 3), NO_KEY}, 1000, HOTKEY_SLEEP_HOST},
+    // num lock + plus, types the time set over the console
+    {{KEY_ID(1, 0), KEY_ID(2, 3), NO_KEY}, 1000, HOTKEY_TIMESTAMP},
+    // num lock + divide, shows what each key does in the current mode. Held
+    // briefly, so it still works where num lock is clear and rolling from
+    // clear onto divide doesn't open it
+    {{KEY_ID(1, 0), KEY_ID(1, 1), NO_KEY}, 500, HOTKEY_HELP},
+    // num lock + backspace, brings up the PIN lock screen
+    {{KEY_ID(1, 0), KEY_ID(0, 3), NO_KEY}, 1000, HOTKEY_LOCK_KEYPAD},
+    // num lock + dot
+    {{KEY_ID(1, 0), KEY_ID(5, 2), NO_KEY}, 2000, HOTKEY_REBOOT},
+    // num lock + multiply, stops all output until pressed again
+    {{KEY_ID(1, 0), KEY_ID(1, 2), NO_KEY}, 1000, HOTKEY_KILL_SWITCH},
+    // num lock + F14, starts a macro recording or cancels it
+    {{KEY_ID(1, 0), KEY_ID(0, 1), NO_KEY}, 0, HOTKEY_MACRO_RECORD},
+    // num lock + 0 and num lock + enter, tap zero two or three times
+    {{KEY_ID(1, 0), KEY_ID(5, 0), NO_KEY}, 0, HOTKEY_DOUBLE_ZERO},
+    {{KEY_ID(1, 0), KEY_ID(4, 3), NO_KEY}, 0, HOTKEY_TRIPLE_ZERO},
+    // num lock + 1 to 9, plays a macro or saves the one being recorded
+    {{KEY_ID(1, 0), KEY_ID(4, 0), NO_KEY}, 0, (EHotkeyAction)(HOTKEY_MACRO_1 + 0)},
+    {{KEY_ID(1, 0), KEY_ID(4, 1), NO_KEY}, 0, (EHotkeyAction)(HOTKEY_MACRO_1 + 1)},
+    {{KEY_ID(1, 0), KEY_ID(4, 2), NO_KEY}, 0, (EHotkeyAction)(HOTKEY_MACRO_1 + 2)},
+    {{KEY_ID(1, 0), KEY_ID(3, 0), NO_KEY}, 0, (EHotkeyAction)(HOTKEY_MACRO_1 + 3)},
+    {{KEY_ID(1, 0), KEY_ID(3, 1), NO_KEY}, 0, (EHotkeyAction)(HOTKEY_MACRO_1 + 4)},
+    {{KEY_ID(1, 0), KEY_ID(3, 2), NO_KEY}, 0, (EHotkeyAction)(HOTKEY_MACRO_1 + 5)},
+    {{KEY_ID(1, 0), KEY_ID(2, 0), NO_KEY}, 0, (EHotkeyAction)(HOTKEY_MACRO_1 + 6)},
+    {{KEY_ID(1, 0), KEY_ID(2, 1), NO_KEY}, 0, (EHotkeyAction)(HOTKEY_MACRO_1 + 7)},
+    {{KEY_ID(1, 0), KEY_ID(2, 2), NO_KEY}, 0, (EHotkeyAction)(HOTKEY_MACRO_1 + 8)},
+    // num lock
+    {{KEY_ID(1, 0), NO_KEY, NO_KEY}, 1000, HOTKEY_NEXT_MODE},
+};
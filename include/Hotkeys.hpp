@@ -0,0 +1,97 @@
+#pragma once
+
+#include "Arduino.h"
+#include "KeyboardConfig.h"
+#include <U8g2lib.h>
+
+#define HOTKEY_MAX_KEYS 3
+
+#define KEY_ID(row, column) ((row) * COLS + (column))
+#define NO_KEY 0xFF
+
+// how long a chord has to be held before its progress is shown, so tapping
+// a key that starts a chord doesn't flash the screen
+#define HOTKEY_FEEDBACK_DELAY 250
+
+enum EHotkeyAction : unsigned char {
+    HOTKEY_NONE,
+    HOTKEY_NEXT_MODE,
+    HOTKEY_GAME_MODE,
+    HOTKEY_LOCK_HOST,
+    HOTKEY_REBOOT,
+    HOTKEY_MACRO_RECORD,
+    HOTKEY_DOUBLE_ZERO,
+    HOTKEY_TRIPLE_ZERO,
+    HOTKEY_KILL_SWITCH,
+    HOTKEY_SLEEP_HOST,
+    HOTKEY_TIMESTAMP,
+    HOTKEY_HELP,
+    HOTKEY_LOCK_KEYPAD,
+    // one action per macro slot, up to HOTKEY_MACRO_1 + MACRO_SLOTS - 1
+    HOTKEY_MACRO_1,
+};
+
+#if ROWS * COLS > 32
+#error "Hotkeys track keys in a 32 bit mask"
+#endif
+
+// what should happen to a key release, see Hotkeys::onRelease
+enum EHotkeyRelease : unsigned char {
+    HOTKEY_PASS,    // deliver the release as usual
+    HOTKEY_TAP,     // the press was held back, deliver press and release now
+    HOTKEY_SWALLOW, // the key triggered a chord, the mode never sees it
+};
+
+typedef struct Hotkey {
+    unsigned char keys[HOTKEY_MAX_KEYS];
+    unsigned short holdMs;
+    EHotkeyAction action;
+} Hotkey;
+
+// Watches the key matrix for the chords listed in HotkeyConfig.h
+class Hotkeys {
+    private:
+        unsigned char active;
+        unsigned long activeSince;
+        bool fired;
+
+        // presses that completed a chord and were kept from the mode
+        unsigned long pending = 0;
+        // held back presses of a chord that fired
+        unsigned long consumed = 0;
+        // keys already down at boot, no part of any chord until let go
+        unsigned long ignored = 0;
+
+        bool tapChordsEnabled = true;
+
+        bool isEnabled(const Hotkey* hotkey) const;
+        bool isHeld(const Hotkey* hotkey, const unsigned long states[COLS][ROWS]) const;
+        unsigned long mask(const Hotkey* hotkey) const;
+        void load(const unsigned char index, Hotkey* hotkey) const;
+
+    public:
+        Hotkeys();
+
+        // Turns the chords without a hold time on or off, e.g. macros and
+        // 00, keys of disabled chords reach the mode as usual
+        void setTapChordsEnabled(const bool enabled);
+
+        // Keeps a key that was held while powering up, e.g. for safe mode,
+        // out of every chord until it's released
+        void ignoreUntilReleased(const unsigned char key);
+
+        // Call once the press is recorded in states. Returns false when the
+        // press completes a chord and must not reach the mode (yet).
+        bool onPress(const unsigned char row, const unsigned char column, const unsigned long states[COLS][ROWS]);
+        EHotkeyRelease onRelease(const unsigned char row, const unsigned char column);
+
+        // Returns the action of a chord that has just been held long enough
+        EHotkeyAction update(const unsigned long states[COLS][ROWS], const unsigned long time);
+
+        // If the key is part of the chord that is currently held down
+        bool isActive(const unsigned char row, const unsigned char column) const;
+
+        // Draws the pending action of a held chord and how long is left on
+        // top of the frame buffer, returns false when there is nothing to show
+        bool draw(U8G2* u8g2, const unsigned long time) const;
+};
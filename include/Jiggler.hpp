@@ -0,0 +1,40 @@
+#pragma once
+
+#include "KeyboardConfig.h"
+#include "KeyboardInterface.hpp"
+
+// seconds between nudges to start with, plus and minus step through
+// jigglerIntervals
+#ifndef JIGGLER_INTERVAL
+#define JIGGLER_INTERVAL 60
+#endif
+
+// Moves the pointer a pixel and back every so often while shown, so the
+// host doesn't lock or sleep. Enter pauses it.
+class Jiggler : public KeyboardInterface {
+    protected:
+        const unsigned char mapping[ROWS][COLS] = {
+            {0, 0, 0, 0},
+            {0, 0, 0, '-'},
+            {0, 0, 0, '+'},
+            {0, 0, 0, '+'},
+            {0, 0, 0, '\n'},
+            {0, 0, 0, '\n'},
+        };
+
+        unsigned short interval = JIGGLER_INTERVAL;
+        bool running = true;
+
+        unsigned long lastNudge = 0;
+        unsigned short nudges = 0;
+        unsigned long shownSecond = 0;
+
+        unsigned char getChar(unsigned char row, unsigned char column) const;
+
+    public:
+        void onPress(char row, char column) override;
+        void draw(U8G2* u8g2) override;
+        void tick(const unsigned long ms) override;
+        void onShow() override;
+        bool describeKey(char row, char column, char* label) const override;
+};
@@ -0,0 +1,42 @@
+#pragma once
+
+#include "Arduino.h"
+#include "Features.h"
+
+// transitions kept, the oldest are dropped first
+#define KEY_TRACE_SIZE 32
+
+// Remembers the latest raw and debounced key transitions for chasing down
+// chatter and missed keys, printed as CSV by the "trace" console command
+class KeyTrace {
+    private:
+        typedef struct KeyTraceEntry {
+            unsigned long time;
+            unsigned char key;
+            unsigned char flags;
+        } KeyTraceEntry;
+
+        KeyTraceEntry entries[KEY_TRACE_SIZE];
+        unsigned char next = 0;
+        unsigned char count = 0;
+
+        unsigned long raw = 0;
+        unsigned long debounced = 0;
+
+        void add(const unsigned char key, const unsigned char flags, const unsigned long time);
+
+    public:
+        // Call for every key on every scan, only changes are stored
+        void update(const unsigned char key, const bool raw, const bool debounced, const unsigned long time);
+
+        void print(Print* out) const;
+        void clear();
+};
+
+#if FEATURE_TRACE
+extern KeyTrace keyTrace;
+
+#define TRACE_KEY(key, raw, debounced, time) keyTrace.update(key, raw, debounced, time)
+#else
+#define TRACE_KEY(key, raw, debounced, time)
+#endif
@@ -2,3 +2,19 @@
 
 #define COLS 4
 #define ROWS 6
+
+// shortcuts sent to the host depend on its OS
+#define HOST_WINDOWS 0
+#define HOST_MACOS 1
+
+#ifndef HOST_PLATFORM
+#define HOST_PLATFORM HOST_WINDOWS
+#endif
+
+// what the top row sends in numpad mode, chords with num lock work either way
+#define TOP_ROW_EDIT 0  // scroll lock, F14, delete and backspace
+#define TOP_ROW_FKEYS 1 // F13 to F16, free for the host to bind
+
+#ifndef NUMPAD_TOP_ROW
+#define NUMPAD_TOP_ROW TOP_ROW_EDIT
+#endif
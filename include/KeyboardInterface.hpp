@@ -2,7 +2,13 @@
 
 #pragma once
 
+// longest key label in the help overlay, with the terminator
+#define HELP_LABEL_SIZE 8
+
 class KeyboardInterface {
+    protected:
+        bool drawNext = true;
+
     public:
         virtual void onPress(char row, char column) {};
         virtual void onRelease(char row, char column) {};
@@ -11,4 +17,17 @@ class KeyboardInterface {
         virtual void tick(const unsigned long ms) {};
         virtual void onShow() {};
         virtual void onHide() {};
+
+        // Modes where num lock is a key of their own, e.g. clear, turn off
+        // the chords that fire without a hold, as rolling from it onto the
+        // next key is just typing there
+        virtual bool allowsTapChords() const { return true; }
+
+        // Writes a short label of what a key does into label for the help
+        // overlay, false if the key does nothing in this mode
+        virtual bool describeKey(char row, char column, char* label) const { return false; }
+
+        // Makes the next draw() render the whole screen again
+        void invalidate() { this->drawNext = true; }
+        bool needsDraw() const { return this->drawNext; }
 };
@@ -0,0 +1,18 @@
+#pragma once
+
+#include "Numpad.hpp"
+#include "Calculator.hpp"
+
+// Numpad that also feeds every key to a calculator and shows its display, so
+// what was typed into the host can be checked at a glance
+class LiveNumpad : public Numpad {
+    protected:
+        Calculator* calculator;
+
+    public:
+        LiveNumpad(Calculator* calculator);
+
+        void onPress(char row, char column) override;
+        void draw(U8G2* u8g2) override;
+        void onShow() override;
+};
@@ -0,0 +1,28 @@
+#pragma once
+
+#include "Arduino.h"
+
+#define LOG_QUEUE_SIZE 64
+
+// Buffers log output and only hands it to Serial when there is room in the
+// USB endpoint, so a host that isn't reading can never stall the scan loop.
+// Output that doesn't fit in the queue is dropped. Once the host set the
+// time every line starts with it.
+class LogQueue : public Print {
+    private:
+        char buffer[LOG_QUEUE_SIZE];
+        unsigned char head = 0;
+        unsigned char count = 0;
+        bool lineStart = true;
+
+        size_t push(uint8_t value);
+
+    public:
+        size_t write(uint8_t value) override;
+        using Print::write;
+
+        // Moves as much queued output to Serial as fits without blocking
+        void process();
+};
+
+extern LogQueue Log;
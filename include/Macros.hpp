@@ -0,0 +1,98 @@
+#pragma once
+
+#include "Arduino.h"
+#include <U8g2lib.h>
+
+#define MACRO_SIZE 48
+// saved macros, played with num lock + 1 to 9
+#define MACRO_SLOTS 9
+// delays are stored in steps of this many milliseconds
+#define MACRO_DELAY_UNIT 10
+#define MACRO_WAIT_UNIT 100
+
+// least time between two reports sent by playback, so a runaway macro can't
+// flood whatever window has focus
+#ifndef MACRO_REPORT_INTERVAL
+#define MACRO_REPORT_INTERVAL 4
+#endif
+
+// A macro is a list of steps, an EMacroStep followed by its argument. Text
+// steps are followed by the characters to type.
+enum EMacroStep : unsigned char {
+    MACRO_END,
+    MACRO_PRESS,   // keycode
+    MACRO_RELEASE, // keycode
+    MACRO_DELAY,   // MACRO_DELAY_UNIT multiples
+    MACRO_WAIT,    // MACRO_WAIT_UNIT multiples, for slow applications
+    MACRO_MODIFIERS, // modifier bits to hold from now on, bit 0 is left ctrl
+    MACRO_REPEAT,  // times to play the steps since the start or last repeat
+    MACRO_TEXT,    // length of the text that follows, typed with the keyboard layout
+    MACRO_TIMESTAMP, // EWallTimeFormat of the current time to type
+};
+
+// Records the keys sent to the host and plays them back with the same timing
+class Macros {
+    private:
+        unsigned char buffer[MACRO_SIZE];
+        unsigned char length = 0;
+
+        bool recording = false;
+        unsigned long lastRecorded;
+
+        bool enabled = true;
+        bool playing = false;
+        unsigned char position;
+        unsigned long nextStep;
+        unsigned char modifiers;
+
+        unsigned char textLeft;
+
+        bool repeating;
+        unsigned char repeatStart;
+        unsigned char repeatsLeft;
+
+        void setModifiers(const unsigned char bits);
+        void runStep(const unsigned char step, const unsigned char value, const unsigned long ms);
+
+        bool append(const EMacroStep step, const unsigned char value);
+        int address(const unsigned char slot) const;
+
+    public:
+        void startRecording();
+        void stopRecording();
+        bool isRecording() const;
+
+        // Adds a step while recording, ignored otherwise
+        void record(const EMacroStep step, const unsigned char value);
+
+        // Loads a saved macro and starts playing it
+        void play(const unsigned char slot);
+        bool isPlaying() const;
+        // Ends playback right away and lets go of its keys
+        void stop();
+        // Safe mode boots with saved macros left alone, the console can
+        // still list and fix them
+        void setEnabled(const bool enabled);
+
+        // Stores the last recording in a slot, an empty recording clears it
+        void save(const unsigned char slot);
+        void erase(const unsigned char slot);
+        // Adds a step to the end of a saved macro, false when it's full
+        bool append(const unsigned char slot, const EMacroStep step, const unsigned char value);
+        bool appendText(const unsigned char slot, const char* text);
+        // Bytes used by the macro in a slot
+        unsigned char used(const unsigned char slot) const;
+
+        // Lists the slots and how full they are
+        void print(Print* out) const;
+        // Writes a slot as the console commands that rebuild it, so it can be
+        // kept and edited as a text file on the host and pasted back
+        void list(const unsigned char slot, Print* out) const;
+
+        void tick(const unsigned long ms);
+
+        // Draws the recording indicator, returns false when not recording
+        bool draw(U8G2* u8g2) const;
+};
+
+extern Macros macros;
@@ -0,0 +1,32 @@
+#pragma once
+
+#include "HID-Project.h"
+#include "KeyboardConfig.h"
+#include "KeyboardInterface.hpp"
+
+// Volume and playback on the keypad: 4, 5 and 6 are previous, play and
+// next, plus and minus the volume and multiply mutes. Keys are held as long
+// as they're down, so the host repeats the volume steps.
+class MediaKeys : public KeyboardInterface {
+    protected:
+        const ConsumerKeycode mapping[ROWS][COLS] = {
+            {HID_CONSUMER_UNASSIGNED, HID_CONSUMER_UNASSIGNED, HID_CONSUMER_UNASSIGNED, HID_CONSUMER_UNASSIGNED},
+            {HID_CONSUMER_UNASSIGNED, MEDIA_STOP, MEDIA_VOLUME_MUTE, MEDIA_VOLUME_DOWN},
+            {HID_CONSUMER_UNASSIGNED, HID_CONSUMER_UNASSIGNED, HID_CONSUMER_UNASSIGNED, MEDIA_VOLUME_UP},
+            {MEDIA_PREVIOUS, MEDIA_PLAY_PAUSE, MEDIA_NEXT, MEDIA_VOLUME_UP},
+            {HID_CONSUMER_UNASSIGNED, HID_CONSUMER_UNASSIGNED, HID_CONSUMER_UNASSIGNED, MEDIA_PLAY_PAUSE},
+            {HID_CONSUMER_UNASSIGNED, HID_CONSUMER_UNASSIGNED, HID_CONSUMER_UNASSIGNED, MEDIA_PLAY_PAUSE},
+        };
+
+        // what was pressed last, shown until the next one
+        ConsumerKeycode last = HID_CONSUMER_UNASSIGNED;
+
+        ConsumerKeycode getKey(unsigned char row, unsigned char column) const;
+
+    public:
+        void onPress(char row, char column) override;
+        void onRelease(char row, char column) override;
+        void draw(U8G2* u8g2) override;
+        void onShow() override;
+        bool describeKey(char row, char column, char* label) const override;
+};
@@ -0,0 +1,18 @@
+#pragma once
+
+#include "Arduino.h"
+
+// Fills the unused RAM between the heap and the stack with a marker, so the
+// deepest the stack ever got can be measured later. Call first in setup().
+void memory_paint();
+
+// RAM taken by globals and statics (.data and .bss)
+unsigned short memory_static();
+
+// RAM currently free between the heap and the stack
+unsigned short memory_free();
+
+// Free bytes the stack has never reached since memory_paint()
+unsigned short memory_stack_headroom();
+
+void memory_print(Print* out);
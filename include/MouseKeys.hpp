@@ -0,0 +1,57 @@
+#pragma once
+
+#include "KeyboardConfig.h"
+#include "KeyboardInterface.hpp"
+
+// how often the pointer moves while a key is held
+#define MOUSE_TICK_MS 20
+#define MOUSE_SCROLL_MS 100
+
+// pixels per tick, speeding up by one every MOUSE_ACCEL_MS held
+#define MOUSE_MIN_SPEED 1
+#define MOUSE_MAX_SPEED 12
+
+#ifndef MOUSE_ACCEL_MS
+#define MOUSE_ACCEL_MS 150
+#endif
+
+// The digits around 5 move the pointer the way they point, faster the longer
+// they're held. 5 is the left button and the point the right one, multiply
+// and minus scroll.
+class MouseKeys : public KeyboardInterface {
+    protected:
+        const unsigned char mapping[ROWS][COLS] = {
+            {0, 0, 0, 0},
+            {0, 0, 'u', 'd'},
+            {'7', '8', '9', 0},
+            {'4', 'L', '6', 0},
+            {'1', '2', '3', 0},
+            {0, 0, 'R', 0},
+        };
+
+        // sum of the directions held, and since when
+        signed char dx = 0;
+        signed char dy = 0;
+        unsigned char moving = 0;
+        unsigned long movingSince = 0;
+
+        signed char wheel = 0;
+        unsigned char buttons = 0;
+
+        unsigned char shownSpeed = 0;
+        unsigned long lastMove = 0;
+        unsigned long lastScroll = 0;
+
+        unsigned char getChar(unsigned char row, unsigned char column) const;
+        void steer(const unsigned char input, const signed char sign);
+        unsigned char speed(const unsigned long ms) const;
+
+    public:
+        void onPress(char row, char column) override;
+        void onRelease(char row, char column) override;
+        void draw(U8G2* u8g2) override;
+        void tick(const unsigned long ms) override;
+        void onShow() override;
+        void onHide() override;
+        bool describeKey(char row, char column, char* label) const override;
+};
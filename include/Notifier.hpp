@@ -0,0 +1,45 @@
+#pragma once
+
+#include "Arduino.h"
+#include <U8g2lib.h>
+
+// events that flash a label in the top left corner
+#define NOTIFY_CAPS_LOCK 1
+#define NOTIFY_NUM_LOCK 2
+#define NOTIFY_SCROLL_LOCK 4
+#define NOTIFY_FIRMWARE 8
+
+#ifndef NOTIFY_EVENTS
+#define NOTIFY_EVENTS (NOTIFY_CAPS_LOCK | NOTIFY_SCROLL_LOCK | NOTIFY_FIRMWARE)
+#endif
+
+// how long a label stays up
+#define NOTIFY_MS 800
+
+// longest label, longer ones are cut off
+#define NOTIFY_LABEL_SIZE 16
+
+// Turns host LED changes and firmware events into short labels on screen,
+// so a state change that happened out of sight isn't missed
+class Notifier {
+    private:
+        unsigned char leds = 0;
+        // in flash
+        const char* text = nullptr;
+        unsigned long shownAt;
+
+        void show(const char* text);
+
+    public:
+        // Flashes a label kept in flash, e.g. post(F("Saved"))
+        void post(const __FlashStringHelper* text);
+
+        // Watches the host LEDs
+        void tick(const unsigned long ms);
+
+        // Draws the current label on top of the frame buffer, returns false
+        // when there is nothing to show
+        bool draw(U8G2* u8g2, const unsigned long time);
+};
+
+extern Notifier notifier;
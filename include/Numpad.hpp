@@ -5,15 +5,20 @@
 
 #pragma once
 
+// characters kept to echo what was typed, as many as fit next to the sprite
+#define NUMPAD_ECHO_SIZE 18
 
 class Numpad : public KeyboardInterface {
     protected:
         unsigned char ledState = 0;
-        bool drawNext = true;
 
     protected:
         const KeyboardKeycode mapping[ROWS][COLS] =  {
+#if NUMPAD_TOP_ROW == TOP_ROW_FKEYS
+            {KEY_F13, KEY_F14, KEY_F15, KEY_F16},
+#else
             {KEY_SCROLL_LOCK, KEY_F14, KEY_DELETE, KEY_BACKSPACE},
+#endif
             {KEY_NUM_LOCK, KEYPAD_DIVIDE, KEYPAD_MULTIPLY, KEYPAD_SUBTRACT},
             {KEYPAD_7, KEYPAD_8, KEYPAD_9, KEYPAD_ADD},
             {KEYPAD_4, KEYPAD_5, KEYPAD_6, KEYPAD_ADD},
@@ -21,7 +26,25 @@ class Numpad : public KeyboardInterface {
             {KEYPAD_0, KEYPAD_0, KEYPAD_DOT, KEYPAD_ENTER},
         };
 
+        // what each key adds to the echo, 0 for nothing and '\b' to erase
+        const unsigned char echoMapping[ROWS][COLS] = {
+#if NUMPAD_TOP_ROW == TOP_ROW_FKEYS
+            {0, 0, 0, 0},
+#else
+            {0, 0, 0, '\b'},
+#endif
+            {0, '/', '*', '-'},
+            {'7', '8', '9', '+'},
+            {'4', '5', '6', '+'},
+            {'1', '2', '3', ' '},
+            {'0', '0', '.', ' '},
+        };
+
+        char echo[NUMPAD_ECHO_SIZE + 1] = "";
+
         KeyboardKeycode getChar(unsigned char row, unsigned char column) const;
+        unsigned char getEcho(unsigned char row, unsigned char column) const;
+        void addEcho(const unsigned char character);
 
     public:
         void onPress(char row, char column) override;
@@ -29,4 +52,5 @@ class Numpad : public KeyboardInterface {
         void draw(U8G2* u8g2) override;
         void tick(const unsigned long ms) override;
         void onShow() override;
+        bool describeKey(char row, char column, char* label) const override;
 };
\ No newline at end of file
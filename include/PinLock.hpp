@@ -0,0 +1,65 @@
+#pragma once
+
+#include "KeyboardConfig.h"
+#include "KeyboardInterface.hpp"
+
+#define PIN_MAX_LENGTH 8
+
+// wrong PINs in a row before input is ignored for a while, the wait
+// doubles with every wrong one after that. Both are kept in EEPROM, so
+// unplugging neither gives new guesses nor cuts the wait short.
+#define PIN_ATTEMPTS 3
+#define PIN_LOCKOUT_MS 30000UL
+#define PIN_MAX_LOCKOUT_MS 960000UL
+
+// minutes without a key press before the keypad locks itself, 0 never
+#ifndef PIN_IDLE_MINUTES
+#define PIN_IDLE_MINUTES 0
+#endif
+
+// Lock screen that keeps all HID output off until the PIN has been typed.
+// Only a hash of the PIN is stored, no PIN means the device never locks.
+class PinLock : public KeyboardInterface {
+    protected:
+        const unsigned char mapping[ROWS][COLS] = {
+            {0, 0, 0, '\b'},
+            {'C', 0, 0, 0},
+            {'7', '8', '9', 0},
+            {'4', '5', '6', 0},
+            {'1', '2', '3', '\n'},
+            {'0', '0', 0, '\n'},
+        };
+
+        char entry[PIN_MAX_LENGTH + 1] = "";
+        bool locked = false;
+        bool wrong = false;
+
+        unsigned char failures = 0;
+        unsigned char lockouts = 0;
+        unsigned long lockoutStart = 0;
+        unsigned long lockoutMs = 0;
+        unsigned long shownSecond = 0;
+
+        unsigned char getChar(unsigned char row, unsigned char column) const;
+        void submit();
+        void saveFailures() const;
+        void startLockout();
+        unsigned long lockoutLeft(const unsigned long ms) const;
+
+    public:
+        void onPress(char row, char column) override;
+        void draw(U8G2* u8g2) override;
+        void tick(const unsigned long ms) override;
+        void onShow() override;
+        bool allowsTapChords() const override { return false; }
+
+        void lock();
+        bool isLocked() const;
+
+        // If a PIN has been stored
+        bool isSet() const;
+        void setPin(const char* pin) const;
+        void clearPin() const;
+};
+
+extern PinLock pinLock;
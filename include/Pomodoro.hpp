@@ -0,0 +1,58 @@
+#pragma once
+
+#include "KeyboardConfig.h"
+#include "KeyboardInterface.hpp"
+
+// interval lengths in minutes, override from platformio.ini
+#ifndef POMODORO_WORK_MINUTES
+#define POMODORO_WORK_MINUTES 25
+#endif
+
+#ifndef POMODORO_BREAK_MINUTES
+#define POMODORO_BREAK_MINUTES 5
+#endif
+
+// how fast the screen flashes when an interval is over
+#define POMODORO_FLASH_MS 250
+
+// Alternates work and break intervals, enter starts and pauses. The next
+// interval starts by itself and the screen flashes until a key is pressed.
+// The clock keeps going while another mode is shown.
+class Pomodoro : public KeyboardInterface {
+    protected:
+        const unsigned char mapping[ROWS][COLS] = {
+            {0, 0, 0, 0},
+            {'C', 0, 0, 's'},
+            {0, 0, 0, '\n'},
+            {0, 0, 0, '\n'},
+            {0, 0, 0, '\n'},
+            {0, 0, 0, '\n'},
+        };
+
+        bool working = true;
+        bool running = false;
+        bool flashing = false;
+
+        // work intervals finished since the last reset
+        unsigned char rounds = 0;
+
+        // while running the interval started at started, while paused elapsed
+        // is how far it got
+        unsigned long started = 0;
+        unsigned long elapsed = 0;
+
+        unsigned long shownSecond = 0;
+
+        unsigned char getChar(unsigned char row, unsigned char column) const;
+        unsigned long length() const;
+        unsigned long progress(const unsigned long ms) const;
+        void next();
+
+    public:
+        void onPress(char row, char column) override;
+        void draw(U8G2* u8g2) override;
+        void tick(const unsigned long ms) override;
+        void onShow() override;
+        bool describeKey(char row, char column, char* label) const override;
+        bool allowsTapChords() const override { return false; }
+};
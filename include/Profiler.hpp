@@ -0,0 +1,40 @@
+#pragma once
+
+#include "Arduino.h"
+#include "Features.h"
+
+enum EProfileSection : unsigned char {
+    PROFILE_LOOP,
+    PROFILE_SCAN,
+    PROFILE_TICK,
+    PROFILE_DRAW,
+    PROFILE_SECTIONS,
+};
+
+// Collects how long each part of the main loop takes, in microseconds,
+// since the report was last printed
+class Profiler {
+    private:
+        unsigned long started[PROFILE_SECTIONS];
+        unsigned long total[PROFILE_SECTIONS];
+        unsigned long peak[PROFILE_SECTIONS];
+        unsigned long count[PROFILE_SECTIONS];
+
+    public:
+        void begin(const EProfileSection section);
+        void end(const EProfileSection section);
+
+        // Prints and resets the collected timings
+        void print(Print* out);
+        void reset();
+};
+
+#if FEATURE_PROFILING
+extern Profiler profiler;
+
+#define PROFILE_BEGIN(section) profiler.begin(section)
+#define PROFILE_END(section) profiler.end(section)
+#else
+#define PROFILE_BEGIN(section)
+#define PROFILE_END(section)
+#endif
@@ -0,0 +1,58 @@
+#pragma once
+
+#include "KeyboardConfig.h"
+#include "KeyboardInterface.hpp"
+#include "HeldKeys.hpp"
+#include <stdint.h>
+
+// longest number shown, 64 binary digits and a sign
+#define PROGRAMMER_VALUE_SIZE 65
+
+// Integer calculator on 64 bits for working with registers and flags. The
+// top row picks the base, digits the base doesn't have are ignored and hex
+// A to F are long presses of 1 to 6. Long presses of the operators are the
+// bitwise ones: plus OR, multiply AND, minus XOR and divide NOT. The point
+// shifts left, held it shifts right.
+class ProgrammerCalculator : public KeyboardInterface {
+    protected:
+        const unsigned char mapping[ROWS][COLS] = {
+            {'D', 'H', 'B', 'O'},
+            {'C', '/', 'x', '-'},
+            {'7', '8', '9', '+'},
+            {'4', '5', '6', '+'},
+            {'1', '2', '3', '\n'},
+            {'0', '0', '<', '\n'},
+        };
+
+        unsigned char base = 10;
+
+        int64_t input = 0;
+        int64_t result = 0;
+        char pendingOperation = 0;
+
+        // the next digit starts a new number instead of adding to input
+        bool staleInput = true;
+        bool error = false;
+
+        // the same deferred short press as the calculator's long press keys
+        HeldKeys heldKeys;
+
+        unsigned char getChar(unsigned char row, unsigned char column) const;
+        bool hasLongPress(const char input) const;
+        void deliver();
+        void doInput(const char input);
+        void doDigit(const unsigned char digit);
+        void doMath(const char op);
+
+        // value in the current base, two's complement outside decimal
+        char* format(char* buffer, const int64_t value) const;
+
+    public:
+        void onPress(char row, char column) override;
+        void onRelease(char row, char column) override;
+        void onLongPress(char row, char column) override;
+        void draw(U8G2* u8g2) override;
+        void onShow() override;
+        bool describeKey(char row, char column, char* label) const override;
+        bool allowsTapChords() const override { return false; }
+};
@@ -0,0 +1,12 @@
+#pragma once
+
+#include <U8g2lib.h>
+
+// The display is 32 pixels high, so at one pixel per module version 3 (29
+// modules) is the largest code that fits. With low error correction that
+// holds 53 characters.
+#define QR_MAX_VERSION 3
+
+// Draws text as a QR code with its top left corner at x, y and returns its
+// width, 0 when the text is too long to fit
+unsigned char qr_draw(U8G2* u8g2, const unsigned char x, const unsigned char y, const char* text);
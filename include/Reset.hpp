@@ -0,0 +1,26 @@
+#pragma once
+
+#include "Arduino.h"
+
+// Resets the board through the watchdog. The bootloader's magic key isn't
+// set, so it jumps straight back into the firmware.
+void reboot() __attribute__((noreturn));
+
+// Caterina clears MCUSR before the firmware runs, so only the resets the
+// firmware causes itself can be told apart. Power on, brown out, the reset
+// pin and uploads all come out as RESET_UNKNOWN.
+enum EResetReason : unsigned char {
+    RESET_WATCHDOG,  // the main loop got stuck, see the crash dump
+    RESET_SOFT,      // reboot()
+    RESET_UNKNOWN,
+};
+
+// Left in RAM that survives a reset, in place of MCUSR
+#define RESET_MARK_SOFT 0x50F7
+#define RESET_MARK_WATCHDOG 0xC4A5
+
+void reset_mark(const unsigned short mark);
+
+// Works out why the board started, call once at the start of setup()
+EResetReason reset_read();
+const __FlashStringHelper* reset_name(const EResetReason reason);
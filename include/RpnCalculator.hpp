@@ -0,0 +1,46 @@
+#pragma once
+
+#include "KeyboardConfig.h"
+#include "KeyboardInterface.hpp"
+#include "Calculator.hpp"
+
+// Reverse polish calculator with a four level stack like the HP ones. Enter
+// pushes the number typed, operators work on the bottom two.
+class RpnCalculator : public KeyboardInterface {
+    protected:
+        const unsigned char mapping[ROWS][COLS] = {
+            {'s', 'r', 'n', 'b'},
+            {'C', '/', 'x', '-'},
+            {'7', '8', '9', '+'},
+            {'4', '5', '6', '+'},
+            {'1', '2', '3', '\n'},
+            {'0', '0', '.', '\n'},
+        };
+
+        // x is the bottom of the stack and what's shown, t the top
+        double x = 0;
+        double y = 0;
+        double z = 0;
+        double t = 0;
+
+        char input[CALC_VALUE_SIZE + 1] = "";
+        bool entering = false;
+
+        // a number typed after enter or clear replaces x instead of pushing
+        bool lift = true;
+        bool error = false;
+
+        unsigned char getChar(unsigned char row, unsigned char column) const;
+        void push();
+        void drop();
+        void finishEntry();
+        void doNumeric(const char input);
+        void doOperation(const char op);
+
+    public:
+        void onPress(char row, char column) override;
+        void draw(U8G2* u8g2) override;
+        void onShow() override;
+        bool describeKey(char row, char column, char* label) const override;
+        bool allowsTapChords() const override { return false; }
+};
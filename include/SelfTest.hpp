@@ -0,0 +1,40 @@
+#pragma once
+
+#include "Arduino.h"
+#include "KeyboardConfig.h"
+#include "KeyboardInterface.hpp"
+
+// how long the warning stays up unless a key dismisses it
+#define SELF_TEST_WARNING_MS 4000
+
+// Checks the matrix at power-up. A column that reads low without any row
+// driven is stuck, and more than one key down before anyone could press
+// them points at a short. Suspect keys are kept out of the scan until they
+// read released, and this mode shows which ones they are.
+class SelfTest : public KeyboardInterface {
+    protected:
+        unsigned long suspects = 0;
+        unsigned char stuckColumns = 0;
+        unsigned char held;
+
+        unsigned long start = 0;
+        bool done = false;
+
+    public:
+        void run(const unsigned char* rowPins, const unsigned char* colPins);
+
+        bool hasFaults() const;
+
+        // The one key held down at power-up, NO_KEY if none or it's a fault
+        unsigned char heldKey() const;
+
+        // If the scan should skip a suspect key, stops once it reads released
+        bool isMasked(const unsigned char key, const bool raw);
+
+        void onPress(char row, char column) override;
+        void draw(U8G2* u8g2) override;
+        void tick(const unsigned long ms) override;
+        bool allowsTapChords() const override { return false; }
+
+        bool isDone() const;
+};
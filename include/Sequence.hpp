@@ -0,0 +1,65 @@
+#pragma once
+
+#include "KeyboardConfig.h"
+#include "KeyboardInterface.hpp"
+
+// where a fresh sequence starts, override from platformio.ini
+#ifndef SEQUENCE_START
+#define SEQUENCE_START 1
+#endif
+
+#ifndef SEQUENCE_STEP
+#define SEQUENCE_STEP 1
+#endif
+
+// zero padded width of the typed number, 0 for none
+#ifndef SEQUENCE_PADDING
+#define SEQUENCE_PADDING 0
+#endif
+
+// the largest number that can be typed in, keeps it in a long
+#define SEQUENCE_DIGITS 9
+
+enum ESequenceSuffix : unsigned char {
+    SEQUENCE_SUFFIX_NONE,
+    SEQUENCE_SUFFIX_TAB,
+    SEQUENCE_SUFFIX_ENTER,
+    SEQUENCE_SUFFIX_COUNT,
+};
+
+#ifndef SEQUENCE_SUFFIX
+#define SEQUENCE_SUFFIX SEQUENCE_SUFFIX_ENTER
+#endif
+
+// Types an incrementing number each time enter or plus is pressed, for
+// labelling and inventory entry. Number keys type in where to continue from.
+class Sequence : public KeyboardInterface {
+    protected:
+        const unsigned char mapping[ROWS][COLS] = {
+            {'s', 0, 0, 0},
+            {'C', 'p', 'i', '-'},
+            {'7', '8', '9', '\n'},
+            {'4', '5', '6', '\n'},
+            {'1', '2', '3', '\n'},
+            {'0', '0', 0, '\n'},
+        };
+
+        long start = SEQUENCE_START;
+        long next = SEQUENCE_START;
+        long step = SEQUENCE_STEP;
+        unsigned char padding = SEQUENCE_PADDING;
+        unsigned char suffix = SEQUENCE_SUFFIX;
+
+        // digits typed since the last number went out
+        unsigned char editing = 0;
+
+        unsigned char getChar(unsigned char row, unsigned char column) const;
+        void type();
+
+    public:
+        void onPress(char row, char column) override;
+        void draw(U8G2* u8g2) override;
+        void onShow() override;
+        bool describeKey(char row, char column, char* label) const override;
+        bool allowsTapChords() const override { return false; }
+};
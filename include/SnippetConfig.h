@@ -0,0 +1,19 @@
+#pragma once
+
+#include <avr/pgmspace.h>
+
+// Phrases typed by the number keys in snippets mode, 1 to 9 and then 0.
+// Characters go through the keyboard layout, \n presses enter.
+//
+// A different table can be used with -D SNIPPET_CONFIG='"MySnippets.h"'.
+const char snippet1[] PROGMEM = "Kind regards,\n";
+const char snippet2[] PROGMEM = "Thanks for getting back to me!";
+const char snippet3[] PROGMEM = "=SUM(";
+const char snippet4[] PROGMEM = "=AVERAGE(";
+
+const char* const snippetTable[] PROGMEM = {
+    snippet1,
+    snippet2,
+    snippet3,
+    snippet4,
+};
@@ -0,0 +1,32 @@
+#pragma once
+
+#include "KeyboardConfig.h"
+#include "KeyboardInterface.hpp"
+
+// Number keys type the phrases listed in SnippetConfig.h, one character
+// per loop so a long one doesn't hold up the rest of the firmware
+class Snippets : public KeyboardInterface {
+    protected:
+        const unsigned char mapping[ROWS][COLS] = {
+            {0, 0, 0, 0},
+            {0, 0, 0, 0},
+            {7, 8, 9, 0},
+            {4, 5, 6, 0},
+            {1, 2, 3, 0},
+            {10, 10, 0, 0},
+        };
+
+        // next character to type from flash, nullptr when idle
+        const char* typing = nullptr;
+        unsigned char selected = 0;
+
+        unsigned char getSnippet(unsigned char row, unsigned char column) const;
+
+    public:
+        void onPress(char row, char column) override;
+        void draw(U8G2* u8g2) override;
+        void tick(const unsigned long ms) override;
+        void onShow() override;
+        bool describeKey(char row, char column, char* label) const override;
+        void onHide() override;
+};
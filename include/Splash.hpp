@@ -0,0 +1,29 @@
+#pragma once
+
+#include "KeyboardInterface.hpp"
+
+// how long the title takes to slide in and how long the splash stays up
+#define SPLASH_SLIDE_MS 600
+#define SPLASH_MS 2000
+
+// Boot screen with the firmware version, any key skips it
+class Splash : public KeyboardInterface {
+    protected:
+        unsigned long start = 0;
+        unsigned long elapsed = 0;
+        bool sliding = true;
+        bool done = false;
+        const char* notice = nullptr;
+
+    public:
+        void onPress(char row, char column) override;
+        void draw(U8G2* u8g2) override;
+        void tick(const unsigned long ms) override;
+        bool allowsTapChords() const override { return false; }
+
+        // Once true the main loop moves on to the first mode
+        bool isDone() const;
+
+        // Shown instead of the version, e.g. why the board had to reset
+        void setNotice(const char* notice);
+};
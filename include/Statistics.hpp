@@ -0,0 +1,42 @@
+#pragma once
+
+#include "KeyboardConfig.h"
+#include "KeyboardInterface.hpp"
+#include "Calculator.hpp"
+
+// Collects data points, enter adds the number typed so far. The operator
+// keys pick which statistic is shown below it.
+class Statistics : public KeyboardInterface {
+    protected:
+        const unsigned char mapping[ROWS][COLS] = {
+            {0, 0, 0, 'R'},
+            {'C', 'n', 's', 'm'},
+            {'7', '8', '9', 'd'},
+            {'4', '5', '6', 'd'},
+            {'1', '2', '3', '\n'},
+            {'0', '0', '.', '\n'},
+        };
+
+        char input[CALC_VALUE_SIZE + 1] = "";
+        char shown = 'n';
+
+        // running mean and squared distance from it (Welford), which stays
+        // accurate with single precision floats unlike a sum of squares
+        unsigned short count = 0;
+        double sum = 0;
+        double mean = 0;
+        double squares = 0;
+
+        unsigned char getChar(unsigned char row, unsigned char column) const;
+        void add(const double value);
+        double deviation() const;
+
+    public:
+        void onPress(char row, char column) override;
+        void draw(U8G2* u8g2) override;
+        void onShow() override;
+        bool describeKey(char row, char column, char* label) const override;
+        bool allowsTapChords() const override { return false; }
+
+        void reset();
+};
@@ -0,0 +1,60 @@
+#pragma once
+
+#include "Arduino.h"
+#include "Features.h"
+#include <U8g2lib.h>
+
+// reports kept for the "hid" console command, the oldest are dropped
+#define TEST_HID_HISTORY 16
+
+// how long "key <row> <column>" holds a key, longer than any debounce
+#define TEST_TAP_MS 80
+
+// Hooks for driving a board from a host side test runner over the console:
+// keys pressed as if on the matrix, a checksum of the frame buffer and the
+// keyboard reports sent since the last look.
+class TestHooks {
+    private:
+        typedef struct TestHidEntry {
+            unsigned char key; // keycode, or a character typed through the layout
+            unsigned char flags;
+        } TestHidEntry;
+
+        U8G2* display = nullptr;
+
+        // injected keys down, the tapping ones let go after TEST_TAP_MS
+        unsigned long down = 0;
+        unsigned long tapping = 0;
+        unsigned long tapStart;
+
+        TestHidEntry reports[TEST_HID_HISTORY];
+        unsigned char next = 0;
+        unsigned char count = 0;
+
+    public:
+        void begin(U8G2* display);
+
+        // Holding and tapping keys by KEY_ID, read by the matrix scan
+        void press(const unsigned char key);
+        void release(const unsigned char key);
+        void tap(const unsigned char key);
+        bool isDown(const unsigned char key, const unsigned long time);
+
+        // Call for every report HidOutput sends
+        void report(const unsigned char key, const bool down, const bool character);
+
+        // "screen <checksum in hex>" of what was last drawn
+        void printScreen(Print* out) const;
+        // One report per line, e.g. "down 0x59", then forgets them
+        void printReports(Print* out);
+};
+
+#if FEATURE_TEST_HOOKS
+extern TestHooks testHooks;
+
+#define TEST_KEY_DOWN(key, time) testHooks.isDown(key, time)
+#define TEST_REPORT(key, down, character) testHooks.report(key, down, character)
+#else
+#define TEST_KEY_DOWN(key, time) false
+#define TEST_REPORT(key, down, character)
+#endif
@@ -0,0 +1,26 @@
+#pragma once
+
+#include "Numpad.hpp"
+
+// keys counted per bucket, the window is the sum of all of them
+#define TYPING_BUCKETS 20
+#define TYPING_BUCKET_MS 3000
+
+// Numpad that counts key presses over the last minute and graphs them, the
+// keys still go to the host
+class TypingMeter : public Numpad {
+    protected:
+        unsigned char buckets[TYPING_BUCKETS] = {};
+        unsigned char bucket = 0;
+        unsigned long bucketStart = 0;
+        unsigned int peak = 0;
+
+        unsigned int rate() const;
+        void advance(const unsigned long ms);
+
+    public:
+        void onPress(char row, char column) override;
+        void draw(U8G2* u8g2) override;
+        void tick(const unsigned long ms) override;
+        void onShow() override;
+};
@@ -0,0 +1,24 @@
+#pragma once
+
+#include "KeyboardConfig.h"
+#include "KeyboardInterface.hpp"
+
+// Passes bytes between the USB serial port and the hardware UART on pins 0
+// (RX) and 1 (TX) while shown, so the keypad doubles as a USB serial
+// adapter. The console is off in the meantime.
+class UartBridge : public KeyboardInterface {
+    protected:
+        unsigned char baudIndex = 0;
+        unsigned long sent = 0;
+        unsigned long received = 0;
+        unsigned long lastDraw = 0;
+
+        unsigned long baud() const;
+
+    public:
+        void onPress(char row, char column) override;
+        void draw(U8G2* u8g2) override;
+        void tick(const unsigned long ms) override;
+        void onShow() override;
+        void onHide() override;
+};
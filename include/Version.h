@@ -0,0 +1,6 @@
+#pragma once
+
+// shown on the boot splash and logged at startup
+#ifndef FIRMWARE_VERSION
+#define FIRMWARE_VERSION "0.2.0"
+#endif
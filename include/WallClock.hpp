@@ -0,0 +1,14 @@
+#pragma once
+
+#include "KeyboardInterface.hpp"
+
+// Shows the time the host set with the "time" console command
+class WallClock : public KeyboardInterface {
+    protected:
+        unsigned long shown = 0;
+
+    public:
+        void draw(U8G2* u8g2) override;
+        void tick(const unsigned long ms) override;
+        void onShow() override;
+};
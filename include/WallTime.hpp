@@ -0,0 +1,45 @@
+#pragma once
+
+#include "Arduino.h"
+
+// The board has no RTC, so the time of day is kept by counting millis()
+// from the last time the host set it, e.g. with "time $(date +%s)" on the
+// console. Times are seconds since 1970 in whatever zone the host sent.
+
+typedef struct WallTime {
+    unsigned short year;
+    unsigned char month;  // 1 to 12
+    unsigned char day;    // 1 to 31
+    unsigned char hour;
+    unsigned char minute;
+    unsigned char second;
+    unsigned char weekday; // 0 is sunday
+} WallTime;
+
+void walltime_set(const unsigned long epoch);
+bool walltime_is_set();
+
+// Seconds since 1970, 0 until the host set the time
+unsigned long walltime_now();
+
+// Moves the clock along, call at least once every 49 days so millis()
+// wrapping around isn't missed
+void walltime_tick();
+
+void walltime_split(const unsigned long epoch, WallTime* time);
+
+enum EWallTimeFormat : unsigned char {
+    WALLTIME_DATETIME, // 2024-01-31 23:59:59
+    WALLTIME_DATE,     // 2024-01-31
+    WALLTIME_TIME,     // 23:59:59
+};
+
+// what the timestamp chord types
+#ifndef TIMESTAMP_FORMAT
+#define TIMESTAMP_FORMAT WALLTIME_DATETIME
+#endif
+
+// Needs 20 bytes whatever the format
+char* walltime_format(char* buffer, const unsigned char size, const unsigned long epoch, const EWallTimeFormat format = WALLTIME_DATETIME);
+
+void walltime_print(Print* out);
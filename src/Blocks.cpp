@@ -0,0 +1,260 @@
+#include "Blocks.hpp"
+#include "Format.hpp"
+#include "Arduino.h"
+#include "Features.h"
+
+#if FEATURE_BLOCKS
+// I, O, T, S, Z, J and L as they spawn
+const unsigned short blockShapes[] PROGMEM = {0x0F00, 0x6600, 0x4E00, 0x6C00, 0xC600, 0x8E00, 0x2E00};
+
+#define BLOCKS_SHAPE_COUNT (sizeof(blockShapes) / sizeof(blockShapes[0]))
+#define BLOCKS_SHAPE_O 1
+
+// score for clearing one to four lines at once
+const unsigned short blockLineScores[] = {40, 100, 300, 1200};
+
+// where the well starts on screen, centered
+#define BLOCKS_LEFT_EDGE ((128 - BLOCKS_WIDTH * BLOCKS_CELL) / 2)
+
+static bool shape_cell(const unsigned short shape, const unsigned char row, const unsigned char column) {
+    return shape & (0x8000 >> (row * 4 + column));
+}
+
+// a quarter turn clockwise within the 4x4 box
+static unsigned short shape_rotate(const unsigned short shape) {
+    unsigned short rotated = 0;
+
+    for (unsigned char row = 0; row < 4; row++) {
+        for (unsigned char column = 0; column < 4; column++) {
+            if (shape_cell(shape, 3 - column, row)) {
+                rotated |= 0x8000 >> (row * 4 + column);
+            }
+        }
+    }
+
+    return rotated;
+}
+
+unsigned char Blocks::getAction(unsigned char row, unsigned char column) const {
+    return this->mapping[row][column];
+}
+
+bool Blocks::fits(const unsigned short shape, const signed char x, const signed char y) const {
+    for (unsigned char row = 0; row < 4; row++) {
+        for (unsigned char column = 0; column < 4; column++) {
+            if (!shape_cell(shape, row, column)) {
+                continue;
+            }
+
+            const signed char wellX = x + column;
+            const signed char wellY = y + row;
+
+            if (wellX < 0 || wellX >= BLOCKS_WIDTH || wellY >= BLOCKS_HEIGHT) {
+                return false;
+            }
+
+            // above the top is open
+            if (wellY >= 0 && (this->well[wellY] & (1 << wellX))) {
+                return false;
+            }
+        }
+    }
+
+    return true;
+}
+
+void Blocks::spawn() {
+    this->pieceType = this->nextType;
+    this->nextType = random(BLOCKS_SHAPE_COUNT);
+    this->piece = pgm_read_word(&blockShapes[this->pieceType]);
+    this->x = (BLOCKS_WIDTH - 4) / 2;
+    this->y = 0;
+
+    if (!this->fits(this->piece, this->x, this->y)) {
+        this->over = true;
+    }
+}
+
+// puts the piece into the well and clears the rows it filled
+void Blocks::lock() {
+    for (unsigned char row = 0; row < 4; row++) {
+        for (unsigned char column = 0; column < 4; column++) {
+            if (shape_cell(this->piece, row, column) && this->y + row >= 0) {
+                this->well[this->y + row] |= 1 << (this->x + column);
+            }
+        }
+    }
+
+    unsigned char cleared = 0;
+
+    for (signed char row = BLOCKS_HEIGHT - 1; row >= 0; row--) {
+        if (this->well[row] != BLOCKS_FULL_ROW) {
+            continue;
+        }
+
+        memmove(this->well + 1, this->well, row * sizeof(this->well[0]));
+        this->well[0] = 0;
+        cleared++;
+        row++; // the row that moved down needs checking too
+    }
+
+    if (cleared > 0) {
+        this->score += blockLineScores[cleared - 1];
+        this->lines += cleared;
+    }
+
+    this->spawn();
+}
+
+// moves the piece down a row, false once it landed
+bool Blocks::step() {
+    if (this->fits(this->piece, this->x, this->y + 1)) {
+        this->y++;
+        return true;
+    }
+
+    this->lock();
+    return false;
+}
+
+void Blocks::onPress(char row, char column) {
+    const unsigned char action = this->getAction(row, column);
+
+    // when the player moves is the only entropy there is
+    this->seed = this->seed * 31 + micros();
+    randomSeed(this->seed);
+
+    if (action == BLOCKS_RESTART) {
+        this->reset();
+        return;
+    }
+
+    if (this->over) {
+        return;
+    }
+
+    switch (action) {
+        case BLOCKS_LEFT:
+            if (this->fits(this->piece, this->x - 1, this->y)) this->x--;
+            break;
+        case BLOCKS_RIGHT:
+            if (this->fits(this->piece, this->x + 1, this->y)) this->x++;
+            break;
+        case BLOCKS_ROTATE: {
+            if (this->pieceType == BLOCKS_SHAPE_O) break;
+
+            const unsigned short rotated = shape_rotate(this->piece);
+
+            // nudge it off a wall if that's all that's in the way
+            static const signed char kicks[] = {0, -1, 1};
+
+            for (const signed char kick : kicks) {
+                if (this->fits(rotated, this->x + kick, this->y)) {
+                    this->piece = rotated;
+                    this->x += kick;
+                    break;
+                }
+            }
+            break;
+        }
+        case BLOCKS_DROP:
+            while (this->step());
+            this->lastStep = millis();
+            break;
+    }
+
+    this->drawNext = true;
+}
+
+void Blocks::drawShape(U8G2* u8g2, const unsigned short shape, const unsigned char left, const signed char top) {
+    for (unsigned char row = 0; row < 4; row++) {
+        for (unsigned char column = 0; column < 4; column++) {
+            if (shape_cell(shape, row, column) && top + row >= 0) {
+                u8g2->drawBox(left + column * BLOCKS_CELL, (top + row) * BLOCKS_CELL, BLOCKS_CELL, BLOCKS_CELL);
+            }
+        }
+    }
+}
+
+void Blocks::draw(U8G2* u8g2) {
+    if (!this->drawNext) return;
+    this->drawNext = false;
+
+    char value[12];
+
+    u8g2->clearBuffer();
+
+    u8g2->drawVLine(BLOCKS_LEFT_EDGE - 2, 0, 32);
+    u8g2->drawVLine(BLOCKS_LEFT_EDGE + BLOCKS_WIDTH * BLOCKS_CELL + 1, 0, 32);
+
+    for (unsigned char row = 0; row < BLOCKS_HEIGHT; row++) {
+        for (unsigned char column = 0; column < BLOCKS_WIDTH; column++) {
+            if (this->well[row] & (1 << column)) {
+                u8g2->drawBox(BLOCKS_LEFT_EDGE + column * BLOCKS_CELL, row * BLOCKS_CELL, BLOCKS_CELL, BLOCKS_CELL);
+            }
+        }
+    }
+
+    if (!this->over) {
+        this->drawShape(u8g2, this->piece, BLOCKS_LEFT_EDGE + this->x * BLOCKS_CELL, this->y);
+    }
+
+    u8g2->setFont(u8g2_font_5x8_tr);
+    u8g2->drawStr(0, 8, "Score");
+    u8g2->drawStr(0, 17, format_int(value, sizeof(value), this->score));
+    u8g2->drawStr(0, 32, format_unit(value, sizeof(value), this->lines, " lines"));
+
+    if (this->over) {
+        u8g2->drawStr(82, 17, "Game over");
+    } else {
+        u8g2->drawStr(82, 8, "Next");
+        this->drawShape(u8g2, pgm_read_word(&blockShapes[this->nextType]), 82, 6);
+    }
+
+    u8g2->sendBuffer();
+}
+
+void Blocks::tick(const unsigned long ms) {
+    if (this->over) {
+        return;
+    }
+
+    const unsigned short speedup = (this->lines / 10) * BLOCKS_SPEEDUP_MS;
+    const unsigned short interval = max(BLOCKS_FASTEST_MS, (int)BLOCKS_START_MS - (int)speedup);
+
+    if (ms - this->lastStep >= interval) {
+        this->lastStep = ms;
+        this->step();
+        this->drawNext = true;
+    }
+}
+
+void Blocks::onShow() {
+    this->reset();
+}
+
+bool Blocks::describeKey(char row, char column, char* label) const {
+    switch (this->getAction(row, column)) {
+        case BLOCKS_LEFT: strcpy_P(label, PSTR("Left")); break;
+        case BLOCKS_RIGHT: strcpy_P(label, PSTR("Right")); break;
+        case BLOCKS_ROTATE: strcpy_P(label, PSTR("Rotate")); break;
+        case BLOCKS_DROP: strcpy_P(label, PSTR("Drop")); break;
+        case BLOCKS_RESTART: strcpy_P(label, PSTR("Restart")); break;
+        default: return false;
+    }
+
+    return true;
+}
+
+void Blocks::reset() {
+    memset(this->well, 0, sizeof(this->well));
+    this->score = 0;
+    this->lines = 0;
+    this->over = false;
+    this->nextType = random(BLOCKS_SHAPE_COUNT);
+    this->spawn();
+    this->lastStep = millis();
+    this->drawNext = true;
+}
+
+#endif
@@ -1,22 +1,59 @@
 #include "Calculator.hpp"
+#include "Format.hpp"
 #include "Arduino.h"
 #include "HID-Project.h"
+#include "HidOutput.hpp"
 #include <EEPROM.h>
 #include <math.h>
 
+// offsets into ADDR_EEPROM_CALC_SETTINGS
+#define CALC_SETTING_PRECISION 0 // result decimals
+#define CALC_SETTING_ROUNDING 1 // ECalcRounding
+#define CALC_SETTING_TAX 2 // tax rate in percent, a double
+#define CALC_SETTING_FRACTIONS (CALC_SETTING_TAX + sizeof(double)) // 1 to show results as fractions
+#define CALC_SETTING_SEPARATOR (CALC_SETTING_FRACTIONS + 1) // thousands separator, 0 for none
+
 typedef union {
     double         d;
     unsigned char  bytes[sizeof(double)];
 } MemorySlot;
 
 void double_to_str(char* buffer, double value) {
-    String(value, CALC_PRECISION).toCharArray(buffer, CALC_VALUE_SIZE + 1);
+    format_double(buffer, CALC_MAX_WIDTH + 1, value, CALC_PRECISION);
+}
+
+double round_to(const double value, const unsigned char precision, const ECalcRounding rounding) {
+    const double scale = pow(10, precision);
+    const double scaled = fabs(value) * scale;
+
+    // past this float has no digits left below the last one shown
+    if (scaled >= 1e6) {
+        return value;
+    }
+
+    double whole = floor(scaled);
+    const double fraction = scaled - whole;
+
+    // float can't hold most halves exactly, 2.135 is 2.13499.. so anything
+    // within its error of one counts
+    const double slack = scaled * 1e-7 + 1e-7;
+
+    switch (rounding) {
+        case CALC_ROUND_HALF_UP:
+            if (fraction + slack >= 0.5) whole++;
+            break;
+        case CALC_ROUND_HALF_EVEN:
+            if (fabs(fraction - 0.5) < slack) {
+                if (fmod(whole, 2) != 0) whole++;
+            } else if (fraction > 0.5) {
+                whole++;
+            }
+            break;
+        default:
+            break;
+    }
 
-    // trim zeros
-    char* back = buffer + strlen(buffer);
-    while((*--back) == '0');
-    if((*back) == '.') back--;
-    *(back+1) = '\0';
+    return copysign(whole / scale, value);
 }
 
 Calculator::Calculator() {
@@ -28,13 +65,105 @@ unsigned char Calculator::getChar(unsigned char row, unsigned char column) const
     return this->mapping[row][column];
 }
 
+bool Calculator::hasLongPress(const char input) const {
+    switch (input) {
+        case 'a':
+        case 'b':
+        case 'c':
+        case 'd':
+        case '+':
+        case '/':
+        case 'x':
+        case '-':
+        case '.':
+        case '\n':
+            return true;
+        default:
+            // digits hold the scientific functions
+            return input >= '0' && input <= '9';
+    }
+}
+
 void Calculator::onPress(const char row, const char column) {
+    const char input = this->getChar(row, column);
+    const char held = this->heldKeys.held();
+
+    // while a memory key is held plus and minus add the shown value to it or
+    // take it off, multiply clears it
+    if (held >= 'a' && held <= 'd' && (input == '+' || input == '-' || input == 'x')) {
+        this->updateMemory(held - 'a', input);
+        this->heldKeys.chord();
+        return;
+    }
+
+    // divide held with multiply is integer division, how many times it
+    // fits, and with minus the remainder
+    if (held == '/' && (input == 'x' || input == '-')) {
+        this->onPress(input == 'x' ? '\\' : 'm');
+        this->heldKeys.chord();
+        return;
+    }
+
+    // the point held with 3 gives pi, with 2 gives e, with minus takes back
+    // the last key, with plus or divide adds or strips tax and with 1 toggles
+    // fractions
+    if (held == '.') {
+        char chord = 0;
+
+        switch (input) {
+            case '3': chord = 'P'; break;
+            case '2': chord = 'U'; break;
+            case '-': chord = 'Z'; break;
+            case '+': chord = 'X'; break;
+            case '/': chord = 'Y'; break;
+            case '1': chord = 'F'; break;
+        }
+
+        if (chord != 0) {
+            this->onPress(chord);
+            this->heldKeys.chord();
+            return;
+        }
+    }
+
+    this->heldKeys.press(KEY_ID(row, column), input, this->hasLongPress(input));
+    this->deliver();
+}
+
+void Calculator::onRelease(const char row, const char column) {
+    this->heldKeys.release(KEY_ID(row, column));
+    this->deliver();
+}
+
+void Calculator::tap(const char row, const char column) {
     this->onPress(this->getChar(row, column));
 }
 
+void Calculator::deliver() {
+    for (char input = this->heldKeys.next(); input != 0; input = this->heldKeys.next()) {
+        this->onPress(input);
+    }
+}
+
 void Calculator::onPress(const char input) {
     this->drawNext = true;
 
+    if (input == 'Z') {
+        this->undo();
+        return;
+    }
+
+    // an error sticks until cleared, or the key that caused it is undone
+    if (this->error && input != 'C') {
+        return;
+    }
+
+    this->saveUndo();
+
+    if (input != 'G') {
+        this->grandTotalShown = false;
+    }
+
     if (input >= '0' && input <= '9') {
         this->doNumeric(input);
     } else {
@@ -42,16 +171,68 @@ void Calculator::onPress(const char input) {
     }
 }
 
-void Calculator::loadMemory(const unsigned char slot) {
-    MemorySlot value;
+void Calculator::saveUndo() {
+    CalcSnapshot* snapshot = &this->snapshot;
 
-    for (unsigned char i = 0; i < sizeof(double); i++) {
-        int address = i + (sizeof(double) * slot) + ADDR_EEPROM_CALC_MEMORY;
-        
-        value.bytes[i] = EEPROM.read(address);
+    strcpy(snapshot->input, this->input);
+    snapshot->result = this->result;
+    snapshot->pendingOperation = this->pendingOperation;
+    snapshot->staleInput = this->staleInput;
+    snapshot->error = this->error;
+    snapshot->afterEquals = this->afterEquals;
+    snapshot->repeatOperand = this->repeatOperand;
+    snapshot->grandTotal = this->grandTotal;
+
+    this->canUndo = true;
+}
+
+void Calculator::undo() {
+    if (!this->canUndo) {
+        return;
     }
 
-    double_to_str(this->input, value.d);
+    const CalcSnapshot* snapshot = &this->snapshot;
+
+    strcpy(this->input, snapshot->input);
+    this->result = snapshot->result;
+    this->pendingOperation = snapshot->pendingOperation;
+    this->staleInput = snapshot->staleInput;
+    this->error = snapshot->error;
+    this->afterEquals = snapshot->afterEquals;
+    this->repeatOperand = snapshot->repeatOperand;
+    this->grandTotal = snapshot->grandTotal;
+
+    this->canUndo = false;
+    this->updateResultBuffer();
+}
+
+double Calculator::readMemory(const unsigned char slot) const {
+    double value;
+
+    EEPROM.get(ADDR_EEPROM_CALC_MEMORY + sizeof(double) * slot, value);
+
+    // erased EEPROM reads as NaN
+    return isnan(value) ? 0 : value;
+}
+
+void Calculator::loadMemory(const unsigned char slot) {
+    double_to_str(this->input, this->readMemory(slot));
+
+    // counts as typed, so an operator right after uses it
+    this->staleInput = false;
+}
+
+void Calculator::updateMemory(const unsigned char slot, const char op) {
+    const double shown = this->staleInput ? this->result : atof(this->getInput());
+    const double value = this->readMemory(slot);
+
+    this->drawNext = true;
+
+    switch (op) {
+        case '+': this->storeMemory(slot, value + shown); break;
+        case '-': this->storeMemory(slot, value - shown); break;
+        case 'x': this->storeMemory(slot, 0); break;
+    }
 }
 
 void Calculator::storeMemory(const unsigned char slot, double data) const {
@@ -66,6 +247,11 @@ void Calculator::storeMemory(const unsigned char slot, double data) const {
 }
 
 void Calculator::onLongPress(const char row, const char column) {
+    // once per hold, the main loop keeps calling while the key stays down
+    if (!this->heldKeys.longPress(KEY_ID(row, column))) {
+        return;
+    }
+
     this->onLongPress(this->getChar(row, column));
 }
 void Calculator::onLongPress(const char input) {
@@ -79,18 +265,57 @@ void Calculator::onLongPress(const char input) {
             this->storeMemory(input - 'a', this->result);
             strcpy(this->input, this->getResult());
             break;
+        case '+':
+            this->onPress('G');
+            break;
+        case '/':
+            this->onPress('%');
+            break;
+        case '-':
+            this->onPress('N');
+            break;
+        case 'x':
+            this->onPress('B');
+            break;
+        // scientific functions of the shown value
+        case '7': this->onPress('S'); break; // sin
+        case '8': this->onPress('O'); break; // cos
+        case '9': this->onPress('T'); break; // tan
+        case '4': this->onPress('L'); break; // ln
+        case '5': this->onPress('K'); break; // log10
+        case '6': this->onPress('E'); break; // e^x
+        case '1': this->onPress('R'); break; // square root
+        case '2': this->onPress('Q'); break; // square
+        case '3': this->onPress('I'); break; // 1/x
+        case '0':
+            this->radians = !this->radians;
+            break;
         case '.':
         case '\n':
-            Keyboard.print(this->getResult());
+#if CALC_TYPE_KEYPAD
+            hid.printKeypad(this->getResult());
+#else
+            hid.print(this->getResult());
+#endif
             break;
     }
 }
 
 void Calculator::doNumeric(const char input) {
+    // digits typed onto 1e+16 would go into the exponent, start over instead
+    if (this->hasExponent()) {
+        this->clearInput();
+    }
+
     if (this->staleInput) {
         this->clearInput();
-    } else if (input == '0' && (this->input[0] == '0' || (this->input[0] == '-' && this->input[1] == '0'))) {
-        return;
+    } else if (strcmp(this->input, "0") == 0 || strcmp(this->input, "-0") == 0) {
+        // no leading zeros, but 0.05 still needs them
+        if (input == '0') {
+            return;
+        }
+
+        this->input[strlen(this->input) - 1] = 0;
     }
 
     this->pushInput(input);
@@ -137,21 +362,38 @@ void Calculator::doOperation(const char op) {
         case '/':
         case '*':
         case 'x':
+        case '\\':
+        case 'm':
             if (this->input[0] != 0 && !this->staleInput) {
-                this->doMath(this->pendingOperation);
+                // a number typed after equals starts over instead of going
+                // onto the last result
+                this->doMath(this->afterEquals ? 0 : this->pendingOperation);
             }
             this->pendingOperation = op;
             this->staleInput = true;
+            this->afterEquals = false;
             break;
         case 'C':
-            if (this->input[0] == 0) {
+            if (this->error) {
+                // clear gets out of the error state and starts over
+                this->error = false;
+                this->clearInput();
+                this->clearResult();
+                this->pendingOperation = 0;
+                this->afterEquals = false;
+            } else if (this->input[0] == 0) {
                 this->clearResult();
                 this->pendingOperation = 0;
+                this->afterEquals = false;
             } else {
                 this->clearInput();
             }
             break;
         case '.':
+            if (this->hasExponent()) {
+                this->clearInput();
+            }
+
             if (this->staleInput) {
                 this->pushInput('0');
             }
@@ -160,9 +402,136 @@ void Calculator::doOperation(const char op) {
             }
             break;
         case '\n':
+            // equals again repeats the last operation, also on a new number,
+            // so 2 + 3 = = gives 8 and 2 + 3 = 5 = does too
+            if (this->afterEquals && !this->staleInput && this->pendingOperation != 0) {
+                this->result = atof(this->getInput());
+                double_to_str(this->input, this->repeatOperand);
+            }
+
+            this->repeatOperand = atof(this->getInput());
             this->doMath(this->pendingOperation);
             this->staleInput = true;
+            this->afterEquals = true;
+
+            if (!this->error) {
+                this->grandTotal += this->result;
+            }
+            break;
+        case '%': {
+            // like a desk calculator, 200 + 10% adds 10% of 200 and
+            // 50 x 20% takes 20% of 50
+            const double value = atof(this->getInput());
+            const bool ofResult = this->pendingOperation == '+' || this->pendingOperation == '-';
+
+            double_to_str(this->input, ofResult ? this->result * value / 100 : value / 100);
+            this->staleInput = false;
+            this->doOperation('\n');
             break;
+        }
+        case 'N':
+            this->negate();
+            break;
+        case 'F':
+            this->setFractions(!this->fractions);
+            break;
+        case 'P':
+        case 'U':
+            // replaces whatever is being typed, with all the digits float has
+            format_double(this->input, CALC_VALUE_SIZE + 1, op == 'P' ? M_PI : M_E, CALC_MAX_PRECISION);
+            this->staleInput = false;
+            break;
+        case 'B':
+            // only what's being typed can be corrected
+            if (!this->staleInput && this->input[0] != 0) {
+                this->input[strlen(this->input) - 1] = 0;
+            }
+            break;
+        case 'S':
+        case 'O':
+        case 'T':
+        case 'L':
+        case 'K':
+        case 'E':
+        case 'R':
+        case 'Q':
+        case 'I':
+        case 'X':
+        case 'Y':
+            this->doFunction(op);
+            break;
+        case 'G':
+            // the first press shows the grand total, a second one clears it
+            if (this->grandTotalShown) {
+                this->grandTotal = 0;
+                this->clearInput();
+                this->grandTotalShown = false;
+            } else {
+                double_to_str(this->input, this->grandTotal);
+                this->staleInput = false;
+                this->grandTotalShown = true;
+            }
+            break;
+    }
+}
+
+// flips the sign of the value being typed, or of the result when nothing is
+void Calculator::negate() {
+    if (this->staleInput) {
+        this->result = -this->result;
+        this->updateResultBuffer();
+        return;
+    }
+
+    const unsigned char length = strlen(this->input);
+
+    if (this->input[0] == '-') {
+        memmove(this->input, this->input + 1, length);
+    } else if (length < CALC_VALUE_SIZE) {
+        memmove(this->input + 1, this->input, length + 1);
+        this->input[0] = '-';
+    }
+}
+
+// replaces the value being typed, or the result when nothing is, with a
+// function of it
+void Calculator::doFunction(const char function) {
+    const bool onResult = this->staleInput;
+    const double value = onResult ? this->result : atof(this->getInput());
+    const double angle = this->radians ? value : value * M_PI / 180;
+    double output;
+
+    switch (function) {
+        case 'S': output = sin(angle); break;
+        case 'O': output = cos(angle); break;
+        case 'T': output = tan(angle); break;
+        case 'L': output = log(value); break;
+        case 'K': output = log10(value); break;
+        case 'E': output = exp(value); break;
+        case 'R': output = sqrt(value); break;
+        case 'Q': output = value * value; break;
+        case 'I': output = 1 / value; break;
+        case 'X': output = value * (1 + this->taxRate / 100); break;
+        case 'Y': output = value / (1 + this->taxRate / 100); break;
+        default: return;
+    }
+
+    this->error = isnan(output) || isinf(output);
+
+    if (this->error) {
+        return;
+    }
+
+    // sin(180) comes out as a tiny remainder instead of 0
+    if (fabs(output) < 1e-6) {
+        output = 0;
+    }
+
+    if (onResult) {
+        this->result = output;
+        this->updateResultBuffer();
+    } else {
+        double_to_str(this->input, output);
     }
 }
 
@@ -176,6 +545,10 @@ bool Calculator::hasPoint() const {
     return false;
 }
 
+bool Calculator::hasExponent() const {
+    return strchr(this->input, 'e') != nullptr;
+}
+
 const char* Calculator::getInput() const {
     return this->input[0] == 0 ? "0" : this->input;
 }
@@ -184,8 +557,13 @@ const char* Calculator::getResult() const {
     return this->resultBuffer;
 }
 
+bool Calculator::hasError() const {
+    return this->error;
+}
+
 void Calculator::doMath(const char op) {
     double input = atof(this->getInput());
+    const double left = this->result;
 
     this->error = false;
 
@@ -201,49 +579,211 @@ void Calculator::doMath(const char op) {
             this->result *= input;
             break;
         case '/':
+        case '\\':
+        case 'm':
             if (input == 0) {
                 this->error = true;
                 return;
             }
 
-            this->result /= input;
+            if (op == '/') {
+                this->result /= input;
+            } else if (op == '\\') {
+                this->result = trunc(this->result / input);
+            } else {
+                this->result = fmod(this->result, input);
+            }
             break;
         default:
             this->result = input;
-            break;
+            this->updateResultBuffer();
+            return;
+    }
+
+    // float tops out around 3.4e38, past it the result would be inf
+    if (isinf(this->result) || isnan(this->result)) {
+        this->error = true;
+        this->result = left;
+        return;
     }
 
+    this->addHistory(left, op, input);
     this->updateResultBuffer();
 }
 
+void Calculator::addHistory(const double left, const char operation, const double right) {
+    CalcHistoryEntry* entry = &this->history[this->historyNext];
+
+    entry->left = left;
+    entry->operation = operation;
+    entry->right = right;
+    entry->result = this->result;
+
+    this->historyNext = (this->historyNext + 1) % CALC_HISTORY_SIZE;
+
+    if (this->historyCount < CALC_HISTORY_SIZE) {
+        this->historyCount++;
+    }
+}
+
+const CalcHistoryEntry* Calculator::getHistory(const unsigned char back) const {
+    if (back >= this->historyCount) {
+        return nullptr;
+    }
+
+    return &this->history[(this->historyNext + CALC_HISTORY_SIZE - 1 - back) % CALC_HISTORY_SIZE];
+}
+
+unsigned char Calculator::getHistoryCount() const {
+    return this->historyCount;
+}
+
+char* Calculator::getExpression(char* buffer, const unsigned char size, const bool full) const {
+    const CalcHistoryEntry* last = this->getHistory(0);
+    char operation = this->pendingOperation;
+
+    if (operation == 'x') {
+        operation = '*';
+    } else if (operation == 'm') {
+        operation = '%';
+    }
+    char value[CALC_VALUE_SIZE + 1];
+
+    buffer[0] = 0;
+
+    if (operation == 0 || size < CALC_EXPRESSION_SIZE) {
+        return buffer;
+    }
+
+    // equals with an operation always leaves it in the history, unless it
+    // failed
+    if (full && this->afterEquals && !this->error && last != nullptr) {
+        double_to_str(value, last->left);
+        strcat(buffer, value);
+        strcat(buffer, " ");
+        strncat(buffer, &operation, 1);
+        strcat(buffer, " ");
+        double_to_str(value, last->right);
+        strcat(buffer, value);
+        strcat(buffer, " =");
+    } else {
+        strcat(buffer, this->getResult());
+        strcat(buffer, " ");
+        strncat(buffer, &operation, 1);
+    }
+
+    return buffer;
+}
+
+bool Calculator::hasMemory() const {
+    for (unsigned char slot = 0; slot < 4; slot++) {
+        if (this->readMemory(slot) != 0) {
+            return true;
+        }
+    }
+
+    return false;
+}
+
 void Calculator::draw(U8G2* u8g2) {
     if (!this->drawNext) return;
 
     this->drawNext = false;
 
     u8g2->clearBuffer();
-    
-    u8g2->setFont(u8g2_font_ncenB08_tr);	// choose a suitable font
-    
-    const char operationStr[] = {this->pendingOperation, 0};
 
-    u8g2->drawStr(0, 10, operationStr);
-    u8g2->drawStr(10, 10, this->getInput());
-    u8g2->drawStr(126 - u8g2->getStrWidth(this->getResult()), 31, this->getResult());
+    // state indicators in the top left corner
+    u8g2->setFont(u8g2_font_5x8_tr);
+
+    if (this->hasMemory()) {
+        u8g2->drawStr(0, 8, "M");
+    }
 
     if (this->error) {
-        u8g2->drawStr(0, 31, "Err");
+        u8g2->drawStr(8, 8, "E");
+    }
+
+    if (this->grandTotal != 0) {
+        u8g2->drawStr(16, 8, "GT");
+    }
+
+    if (this->radians) {
+        u8g2->drawStr(30, 8, "RAD");
+    }
+
+    // the running total and what will be done with the next value, or the
+    // whole calculation after equals
+    char expression[CALC_EXPRESSION_SIZE];
+
+    this->getExpression(expression, sizeof(expression));
+
+    if (u8g2->getStrWidth(expression) > 128) {
+        this->getExpression(expression, sizeof(expression), false);
+    }
+
+    u8g2->drawStr(128 - u8g2->getStrWidth(expression), 8, expression);
+
+    // the main line shows what's being typed, otherwise the result
+    const char* value = this->error ? "Error" : this->staleInput ? this->getResult() : this->getInput();
+    char shown[CALC_SHOWN_SIZE];
+
+    if (this->error || !this->staleInput) {
+        // shown as it is
+    } else if (this->fractions && format_fraction(shown, sizeof(shown), this->result, CALC_MAX_DENOMINATOR)) {
+        value = shown;
+    } else if (this->separator != 0) {
+        // only on screen, typing the result out needs it plain
+        strcpy(shown, value);
+        value = format_group(shown, sizeof(shown), this->separator);
+    }
+
+    u8g2->setFont(u8g2_font_ncenB14_tr);
+
+    if (u8g2->getStrWidth(value) > 128) {
+        u8g2->setFont(u8g2_font_ncenB08_tr);
     }
 
+    u8g2->drawStr(128 - u8g2->getStrWidth(value), 31, value);
+
     u8g2->sendBuffer();
 }
 
 void Calculator::onShow() {
     this->drawNext = true;
 
+    this->loadSettings();
+    this->heldKeys.clear();
+
     this->clearInput();
     this->clearResult();
     this->pendingOperation = 0;
+    this->afterEquals = false;
+    this->grandTotal = 0;
+    this->canUndo = false;
+}
+
+bool Calculator::describeKey(const char row, const char column, char* label) const {
+    const char input = this->getChar(row, column);
+
+    switch (input) {
+        case 'a':
+        case 'b':
+        case 'c':
+        case 'd':
+            // recall, long press stores
+            label[0] = 'M';
+            label[1] = '1' + (input - 'a');
+            label[2] = 0;
+            break;
+        case 'C': strcpy_P(label, PSTR("Clear")); break;
+        case '\n': strcpy_P(label, PSTR("=")); break;
+        default:
+            label[0] = input;
+            label[1] = 0;
+            break;
+    }
+
+    return true;
 }
 
 void Calculator::clearInput() {
@@ -257,5 +797,84 @@ void Calculator::clearResult() {
 }
 
 void Calculator::updateResultBuffer() {
-    double_to_str(this->resultBuffer, this->result);
+    const double shown = round_to(this->result, this->precision, this->rounding);
+
+    format_double(this->resultBuffer, CALC_MAX_WIDTH + 1, shown, this->precision);
+}
+
+void Calculator::setPrecision(const unsigned char precision) {
+    this->precision = min(precision, CALC_MAX_PRECISION);
+    this->saveSettings();
+    this->updateResultBuffer();
+    this->drawNext = true;
+}
+
+void Calculator::setRounding(const ECalcRounding rounding) {
+    this->rounding = rounding < CALC_ROUND_COUNT ? rounding : CALC_ROUND_HALF_UP;
+    this->saveSettings();
+    this->updateResultBuffer();
+    this->drawNext = true;
+}
+
+void Calculator::setTaxRate(const double percent) {
+    this->taxRate = percent;
+    this->saveSettings();
+}
+
+double Calculator::getTaxRate() const {
+    return this->taxRate;
+}
+
+unsigned char Calculator::getPrecision() const {
+    return this->precision;
+}
+
+ECalcRounding Calculator::getRounding() const {
+    return this->rounding;
+}
+
+void Calculator::loadSettings() {
+    const unsigned char precision = EEPROM.read(ADDR_EEPROM_CALC_SETTINGS + CALC_SETTING_PRECISION);
+    const unsigned char rounding = EEPROM.read(ADDR_EEPROM_CALC_SETTINGS + CALC_SETTING_ROUNDING);
+
+    // erased EEPROM reads as 0xFF, which leaves the defaults
+    this->precision = precision <= CALC_MAX_PRECISION ? precision : CALC_PRECISION;
+    this->rounding = rounding < CALC_ROUND_COUNT ? (ECalcRounding)rounding : CALC_ROUND_HALF_UP;
+
+    double taxRate;
+    EEPROM.get(ADDR_EEPROM_CALC_SETTINGS + CALC_SETTING_TAX, taxRate);
+    this->taxRate = isnan(taxRate) ? CALC_TAX_RATE : taxRate;
+
+    this->fractions = EEPROM.read(ADDR_EEPROM_CALC_SETTINGS + CALC_SETTING_FRACTIONS) == 1;
+
+    const char separator = EEPROM.read(ADDR_EEPROM_CALC_SETTINGS + CALC_SETTING_SEPARATOR);
+    this->separator = separator == ' ' || separator == ',' ? separator : 0;
+}
+
+void Calculator::saveSettings() const {
+    EEPROM.update(ADDR_EEPROM_CALC_SETTINGS + CALC_SETTING_PRECISION, this->precision);
+    EEPROM.update(ADDR_EEPROM_CALC_SETTINGS + CALC_SETTING_ROUNDING, this->rounding);
+    EEPROM.put(ADDR_EEPROM_CALC_SETTINGS + CALC_SETTING_TAX, this->taxRate);
+    EEPROM.update(ADDR_EEPROM_CALC_SETTINGS + CALC_SETTING_FRACTIONS, this->fractions ? 1 : 0);
+    EEPROM.update(ADDR_EEPROM_CALC_SETTINGS + CALC_SETTING_SEPARATOR, this->separator);
+}
+
+void Calculator::setSeparator(const char separator) {
+    this->separator = separator;
+    this->saveSettings();
+    this->drawNext = true;
+}
+
+char Calculator::getSeparator() const {
+    return this->separator;
+}
+
+void Calculator::setFractions(const bool fractions) {
+    this->fractions = fractions;
+    this->saveSettings();
+    this->drawNext = true;
+}
+
+bool Calculator::getFractions() const {
+    return this->fractions;
 }
\ No newline at end of file
@@ -0,0 +1,286 @@
+#include "Console.hpp"
+#include "CrashDump.hpp"
+#include "Memory.hpp"
+#include "Profiler.hpp"
+#include "Reset.hpp"
+#include "Macros.hpp"
+#include "PinLock.hpp"
+#include "WallTime.hpp"
+#include "KeyTrace.hpp"
+#include "TestHooks.hpp"
+#include "Hotkeys.hpp"
+#include "Features.h"
+
+#if FEATURE_CALCULATOR
+#include "Calculator.hpp"
+
+// lives in main.cpp with the other modes
+extern Calculator calculator;
+
+// console only, so the names stay in flash
+const char roundUp[] PROGMEM = "up";
+const char roundEven[] PROGMEM = "even";
+const char roundTruncate[] PROGMEM = "truncate";
+const char* const roundingNames[CALC_ROUND_COUNT] PROGMEM = {roundUp, roundEven, roundTruncate};
+
+const char separatorNone[] PROGMEM = "none";
+const char separatorSpace[] PROGMEM = "space";
+const char separatorComma[] PROGMEM = "comma";
+const char* const separatorNames[] PROGMEM = {separatorNone, separatorSpace, separatorComma};
+const char separators[] = {0, ' ', ','};
+
+#define SEPARATOR_COUNT sizeof(separators)
+#endif
+
+void Console::process() {
+    while (Serial.available() > 0) {
+        const char value = Serial.read();
+
+        if (value == '\r' || value == '\n') {
+            this->line[this->length] = 0;
+            this->length = 0;
+
+            if (this->line[0] != 0) {
+                this->run(this->line);
+            }
+        } else if (this->length < CONSOLE_LINE_SIZE) {
+            this->line[this->length++] = value;
+        }
+    }
+}
+
+void Console::run(char* command) {
+    char* args = strchr(command, ' ');
+
+    if (args != nullptr) {
+        *args++ = 0;
+    } else {
+        args = command + strlen(command);
+    }
+
+#if FEATURE_PIN_LOCK
+    // macros can hold secrets, nothing works until the keypad is unlocked
+    if (pinLock.isLocked()) {
+        Serial.println(F("locked"));
+        return;
+    }
+#endif
+
+    if (strcmp(command, "crash") == 0) {
+        if (strcmp(args, "clear") == 0) {
+            crashdump_clear();
+        }
+
+        crashdump_print(&Serial);
+    } else if (strcmp(command, "mem") == 0) {
+        memory_print(&Serial);
+    } else if (strcmp(command, "macros") == 0) {
+        if (strncmp(args, "delete ", 7) == 0) {
+            const int slot = atoi(args + 7);
+
+            if (slot >= 1 && slot <= MACRO_SLOTS) {
+                macros.erase(slot - 1);
+            }
+        } else if (strncmp(args, "add ", 4) == 0) {
+            this->editMacro(args + 4);
+        } else if (strncmp(args, "list ", 5) == 0) {
+            const int slot = atoi(args + 5);
+
+            if (slot >= 1 && slot <= MACRO_SLOTS) {
+                macros.list(slot - 1, &Serial);
+            }
+
+            return;
+        }
+
+        macros.print(&Serial);
+#if FEATURE_PIN_LOCK
+    } else if (strcmp(command, "pin") == 0) {
+        if (strcmp(args, "clear") == 0) {
+            pinLock.clearPin();
+        } else if (args[0] != 0 && strlen(args) <= PIN_MAX_LENGTH && strspn(args, "0123456789") == strlen(args)) {
+            pinLock.setPin(args);
+        } else {
+            Serial.println(F("usage: pin <digits>|clear"));
+            return;
+        }
+
+        Serial.println(pinLock.isSet() ? F("PIN set") : F("no PIN"));
+#endif
+#if FEATURE_CALCULATOR
+    } else if (strcmp(command, "calc") == 0) {
+        this->calcSettings(args);
+#endif
+    } else if (strcmp(command, "time") == 0) {
+        if (args[0] != 0) {
+            walltime_set(strtoul(args, nullptr, 10));
+        }
+
+        walltime_print(&Serial);
+    } else if (strcmp(command, "reboot") == 0) {
+        reboot();
+#if FEATURE_PROFILING
+    } else if (strcmp(command, "perf") == 0) {
+        profiler.print(&Serial);
+#endif
+#if FEATURE_TRACE
+    } else if (strcmp(command, "trace") == 0) {
+        if (strcmp(args, "clear") == 0) {
+            keyTrace.clear();
+        } else {
+            keyTrace.print(&Serial);
+        }
+#endif
+#if FEATURE_TEST_HOOKS
+    } else if (strcmp(command, "key") == 0) {
+        this->injectKey(args);
+    } else if (strcmp(command, "screen") == 0) {
+        testHooks.printScreen(&Serial);
+    } else if (strcmp(command, "hid") == 0) {
+        testHooks.printReports(&Serial);
+#endif
+    } else {
+        Serial.print(F("unknown command: "));
+        Serial.println(command);
+    }
+}
+
+// "<slot> <step> <value>", where step is one of key, press, release,
+// delay (ms), mods (modifier bits), repeat (times), stamp (0 date and time,
+// 1 date, 2 time) or text (the rest of the line)
+void Console::editMacro(char* args) {
+    char* step = strchr(args, ' ');
+    char* value = step != nullptr ? strchr(step + 1, ' ') : nullptr;
+
+    if (value == nullptr) {
+        Serial.println(F("usage: macros add <slot> <step> <value>"));
+        return;
+    }
+
+    *step++ = 0;
+    *value++ = 0;
+
+    const int slot = atoi(args) - 1;
+    const unsigned long number = strtoul(value, nullptr, 0);
+    bool added = false;
+
+    if (slot < 0 || slot >= MACRO_SLOTS) {
+        Serial.println(F("no such slot"));
+        return;
+    }
+
+    if (strcmp(step, "key") == 0) {
+        added = macros.append(slot, MACRO_PRESS, number) && macros.append(slot, MACRO_RELEASE, number);
+    } else if (strcmp(step, "press") == 0) {
+        added = macros.append(slot, MACRO_PRESS, number);
+    } else if (strcmp(step, "release") == 0) {
+        added = macros.append(slot, MACRO_RELEASE, number);
+    } else if (strcmp(step, "delay") == 0) {
+        if (number <= 255UL * MACRO_DELAY_UNIT) {
+            added = macros.append(slot, MACRO_DELAY, number / MACRO_DELAY_UNIT);
+        } else {
+            added = macros.append(slot, MACRO_WAIT, min(number / MACRO_WAIT_UNIT, 255UL));
+        }
+    } else if (strcmp(step, "mods") == 0) {
+        added = macros.append(slot, MACRO_MODIFIERS, number);
+    } else if (strcmp(step, "repeat") == 0) {
+        added = macros.append(slot, MACRO_REPEAT, number);
+    } else if (strcmp(step, "stamp") == 0) {
+        added = macros.append(slot, MACRO_TIMESTAMP, number);
+    } else if (strcmp(step, "text") == 0) {
+        added = macros.appendText(slot, value);
+    } else {
+        Serial.println(F("unknown step"));
+        return;
+    }
+
+    if (!added) {
+        Serial.println(F("macro is full"));
+    }
+}
+
+#if FEATURE_CALCULATOR
+// "precision <decimals>", "rounding up|even|truncate", "tax <percent>" or
+// "separator none|space|comma", prints the settings either way
+void Console::calcSettings(char* args) {
+    if (strncmp(args, "precision ", 10) == 0) {
+        calculator.setPrecision(atoi(args + 10));
+    } else if (strncmp(args, "rounding ", 9) == 0) {
+        unsigned char rounding = 0;
+
+        while (rounding < CALC_ROUND_COUNT && strcmp_P(args + 9, (const char*)pgm_read_ptr(&roundingNames[rounding])) != 0) rounding++;
+
+        if (rounding == CALC_ROUND_COUNT) {
+            Serial.println(F("usage: calc rounding up|even|truncate"));
+            return;
+        }
+
+        calculator.setRounding((ECalcRounding)rounding);
+    } else if (strncmp(args, "tax ", 4) == 0) {
+        calculator.setTaxRate(atof(args + 4));
+    } else if (strncmp(args, "separator ", 10) == 0) {
+        unsigned char separator = 0;
+
+        while (separator < SEPARATOR_COUNT && strcmp_P(args + 10, (const char*)pgm_read_ptr(&separatorNames[separator])) != 0) separator++;
+
+        if (separator == SEPARATOR_COUNT) {
+            Serial.println(F("usage: calc separator none|space|comma"));
+            return;
+        }
+
+        calculator.setSeparator(separators[separator]);
+    } else if (args[0] != 0) {
+        Serial.println(F("usage: calc [precision <decimals>|rounding <mode>|tax <percent>|separator <name>]"));
+        return;
+    }
+
+    Serial.print(F("precision "));
+    Serial.print(calculator.getPrecision(), DEC);
+    Serial.print(F(", rounding "));
+    Serial.print((const __FlashStringHelper*)pgm_read_ptr(&roundingNames[calculator.getRounding()]));
+    Serial.print(F(", tax "));
+    Serial.print(calculator.getTaxRate());
+    Serial.print(F("%, separator "));
+
+    for (unsigned char i = 0; i < SEPARATOR_COUNT; i++) {
+        if (separators[i] == calculator.getSeparator()) {
+            Serial.println((const __FlashStringHelper*)pgm_read_ptr(&separatorNames[i]));
+        }
+    }
+}
+#endif
+
+#if FEATURE_TEST_HOOKS
+// "<row> <column>" taps the key, "down <row> <column>" and
+// "up <row> <column>" hold it and let it go
+void Console::injectKey(char* args) {
+    char action = 't';
+
+    if (strncmp(args, "down ", 5) == 0) {
+        action = 'd';
+        args += 5;
+    } else if (strncmp(args, "up ", 3) == 0) {
+        action = 'u';
+        args += 3;
+    }
+
+    char* end;
+    const long row = strtol(args, &end, 10);
+    const long column = strtol(end, &end, 10);
+
+    if (*end != 0 || row < 0 || row >= ROWS || column < 0 || column >= COLS) {
+        Serial.println(F("usage: key [down|up] <row> <column>"));
+        return;
+    }
+
+    const unsigned char key = KEY_ID(row, column);
+
+    if (action == 'd') {
+        testHooks.press(key);
+    } else if (action == 'u') {
+        testHooks.release(key);
+    } else {
+        testHooks.tap(key);
+    }
+}
+#endif
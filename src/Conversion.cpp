@@ -0,0 +1,77 @@
+#include "Conversion.hpp"
+#include "Arduino.h"
+
+typedef struct Unit {
+    char name[UNIT_NAME_SIZE];
+    EUnitCategory category;
+    // base = value * factor + offset
+    double factor;
+    double offset;
+} Unit;
+
+// in EUnit order, base units are metres, grams, kelvin and bytes
+const Unit unitTable[UNIT_COUNT] PROGMEM = {
+    {"mm", UNIT_LENGTH, 0.001, 0},
+    {"cm", UNIT_LENGTH, 0.01, 0},
+    {"m", UNIT_LENGTH, 1, 0},
+    {"km", UNIT_LENGTH, 1000, 0},
+    {"in", UNIT_LENGTH, 0.0254, 0},
+    {"ft", UNIT_LENGTH, 0.3048, 0},
+    {"yd", UNIT_LENGTH, 0.9144, 0},
+    {"mi", UNIT_LENGTH, 1609.344, 0},
+    {"g", UNIT_MASS, 1, 0},
+    {"kg", UNIT_MASS, 1000, 0},
+    {"oz", UNIT_MASS, 28.349523, 0},
+    {"lb", UNIT_MASS, 453.59237, 0},
+    {"C", UNIT_TEMPERATURE, 1, 273.15},
+    {"F", UNIT_TEMPERATURE, 5.0 / 9, 273.15 - 32 * 5.0 / 9},
+    {"K", UNIT_TEMPERATURE, 1, 0},
+    {"B", UNIT_DATA, 1, 0},
+    {"kB", UNIT_DATA, 1e3, 0},
+    {"MB", UNIT_DATA, 1e6, 0},
+    {"GB", UNIT_DATA, 1e9, 0},
+    {"KiB", UNIT_DATA, 1024.0, 0},
+    {"MiB", UNIT_DATA, 1024.0 * 1024, 0},
+    {"GiB", UNIT_DATA, 1024.0 * 1024 * 1024, 0},
+};
+
+static void conversion_load(Unit* unit, const EUnit index) {
+    memcpy_P(unit, &unitTable[index], sizeof(Unit));
+}
+
+bool conversion_convert(const double value, const EUnit from, const EUnit to, double* result) {
+    if (from >= UNIT_COUNT || to >= UNIT_COUNT) {
+        return false;
+    }
+
+    Unit source;
+    Unit target;
+
+    conversion_load(&source, from);
+    conversion_load(&target, to);
+
+    if (source.category != target.category) {
+        return false;
+    }
+
+    const double base = value * source.factor + source.offset;
+    *result = (base - target.offset) / target.factor;
+
+    return true;
+}
+
+EUnitCategory conversion_category(const EUnit unit) {
+    Unit entry;
+    conversion_load(&entry, unit);
+
+    return entry.category;
+}
+
+char* conversion_name(char* name, const EUnit unit) {
+    Unit entry;
+    conversion_load(&entry, unit);
+
+    strcpy(name, entry.name);
+
+    return name;
+}
@@ -0,0 +1,154 @@
+#include "Converter.hpp"
+#include "HidOutput.hpp"
+#include "Format.hpp"
+#include "Arduino.h"
+
+// decimals of the converted value
+#define CONVERTER_PRECISION 4
+
+const char unitCategoryNames[UNIT_CATEGORY_COUNT][12] PROGMEM = {"Length", "Mass", "Temperature", "Data"};
+
+// the unit after unit in the same category, units of a category are next to
+// each other in EUnit
+static EUnit unit_next(const EUnit unit) {
+    const EUnitCategory category = conversion_category(unit);
+    unsigned char next = unit;
+
+    do {
+        next = (next + 1) % UNIT_COUNT;
+    } while (conversion_category((EUnit)next) != category);
+
+    return (EUnit)next;
+}
+
+unsigned char Converter::getChar(unsigned char row, unsigned char column) const {
+    return this->mapping[row][column];
+}
+
+// starts the category with its first two units
+void Converter::pick(const EUnitCategory category) {
+    unsigned char unit = 0;
+
+    while (conversion_category((EUnit)unit) != category) unit++;
+
+    this->from = (EUnit)unit;
+    this->to = unit_next(this->from);
+}
+
+bool Converter::convert(double* result) const {
+    return conversion_convert(atof(this->input), this->from, this->to, result);
+}
+
+void Converter::onPress(char row, char column) {
+    const unsigned char input = this->getChar(row, column);
+    const unsigned char length = strlen(this->input);
+
+    this->drawNext = true;
+
+    switch (input) {
+        case 'l': this->pick(UNIT_LENGTH); break;
+        case 'm': this->pick(UNIT_MASS); break;
+        case 't': this->pick(UNIT_TEMPERATURE); break;
+        case 'd': this->pick(UNIT_DATA); break;
+        case 'f':
+            // converting to the same unit would show the same number twice
+            do this->from = unit_next(this->from); while (this->from == this->to);
+            break;
+        case 'o':
+            do this->to = unit_next(this->to); while (this->to == this->from);
+            break;
+        case 's': {
+            const EUnit from = this->from;
+
+            this->from = this->to;
+            this->to = from;
+            break;
+        }
+        case 'C':
+            this->input[0] = 0;
+            break;
+        case '-':
+            // temperatures go below zero
+            if (length > 0 && this->input[0] == '-') {
+                memmove(this->input, this->input + 1, length);
+            } else if (length < CALC_VALUE_SIZE) {
+                memmove(this->input + 1, this->input, length + 1);
+                this->input[0] = '-';
+            }
+            break;
+        case '\n': {
+            double result;
+            char text[CALC_VALUE_SIZE + 1];
+
+            if (length > 0 && this->convert(&result)) {
+                hid.printKeypad(format_double(text, sizeof(text), result, CONVERTER_PRECISION));
+            }
+            break;
+        }
+        case '.':
+            if (strchr(this->input, '.') != nullptr) {
+                break;
+            }
+            // fall through
+        default:
+            if (length < CALC_VALUE_SIZE) {
+                this->input[length] = input;
+                this->input[length + 1] = 0;
+            }
+            break;
+    }
+}
+
+void Converter::draw(U8G2* u8g2) {
+    if (!this->drawNext) return;
+    this->drawNext = false;
+
+    char text[CALC_VALUE_SIZE + 1];
+    char name[UNIT_NAME_SIZE];
+    double result;
+
+    u8g2->clearBuffer();
+    u8g2->setFont(u8g2_font_5x8_tr);
+    strcpy_P(text, unitCategoryNames[conversion_category(this->from)]);
+    u8g2->drawStr(0, 8, text);
+
+    u8g2->setFont(u8g2_font_ncenB08_tr);
+    u8g2->drawStr(0, 20, this->input[0] != 0 ? this->input : "0");
+    u8g2->drawStr(104, 20, conversion_name(name, this->from));
+
+    if (this->convert(&result)) {
+        u8g2->drawStr(0, 31, "=");
+        u8g2->drawStr(10, 31, format_double(text, sizeof(text), result, CONVERTER_PRECISION));
+    }
+
+    u8g2->drawStr(104, 31, conversion_name(name, this->to));
+
+    u8g2->sendBuffer();
+}
+
+void Converter::onShow() {
+    this->drawNext = true;
+}
+
+bool Converter::describeKey(char row, char column, char* label) const {
+    const unsigned char input = this->getChar(row, column);
+
+    switch (input) {
+        case 'l': strcpy_P(label, PSTR("Length")); break;
+        case 'm': strcpy_P(label, PSTR("Mass")); break;
+        case 't': strcpy_P(label, PSTR("Temp")); break;
+        case 'd': strcpy_P(label, PSTR("Data")); break;
+        case 'f': strcpy_P(label, PSTR("From")); break;
+        case 'o': strcpy_P(label, PSTR("To")); break;
+        case 's': strcpy_P(label, PSTR("Swap")); break;
+        case 'C': strcpy_P(label, PSTR("Clear")); break;
+        case '-': strcpy_P(label, PSTR("+/-")); break;
+        case '\n': strcpy_P(label, PSTR("Type")); break;
+        default:
+            label[0] = input;
+            label[1] = 0;
+            break;
+    }
+
+    return true;
+}
@@ -0,0 +1,142 @@
+#include "Countdown.hpp"
+#include "Format.hpp"
+#include "Arduino.h"
+
+unsigned char Countdown::getChar(unsigned char row, unsigned char column) const {
+    return this->mapping[row][column];
+}
+
+unsigned long Countdown::left(const unsigned long ms) const {
+    if (!this->running) {
+        return this->remaining;
+    }
+
+    return (long)(this->deadline - ms) > 0 ? this->deadline - ms : 0;
+}
+
+void Countdown::onPress(char row, char column) {
+    const unsigned char input = this->getChar(row, column);
+    const unsigned long ms = millis();
+
+    this->drawNext = true;
+
+    if (this->ringing) {
+        // the key only silences it
+        this->ringing = false;
+        return;
+    }
+
+    if (input >= '0' && input <= '9') {
+        if (!this->running) {
+            if (this->remaining > 0) {
+                // typing over a paused one starts a new time
+                this->remaining = 0;
+                this->entered = 0;
+            }
+
+            // past 99:99 the first digit drops off
+            this->entered = (this->entered * 10 + (input - '0')) % 10000;
+        }
+
+        return;
+    }
+
+    switch (input) {
+        case '\n':
+            if (this->running) {
+                this->remaining = this->left(ms);
+                this->running = false;
+            } else {
+                if (this->remaining == 0) {
+                    this->remaining = ((this->entered / 100) * 60UL + this->entered % 100) * 1000;
+                }
+
+                if (this->remaining > 0) {
+                    this->deadline = ms + this->remaining;
+                    this->running = true;
+                }
+            }
+            break;
+        case 'C':
+            this->running = false;
+            this->remaining = 0;
+            this->entered = 0;
+            break;
+    }
+}
+
+void Countdown::draw(U8G2* u8g2) {
+    if (!this->drawNext) return;
+    this->drawNext = false;
+
+    const unsigned long ms = millis();
+    char text[8];
+
+    u8g2->clearBuffer();
+
+    if (this->ringing && (ms / COUNTDOWN_FLASH_MS) % 2 == 0) {
+        u8g2->drawBox(0, 0, 128, 32);
+        u8g2->setDrawColor(0);
+    }
+
+    u8g2->setFont(u8g2_font_5x8_tr);
+
+    if (this->ringing) {
+        u8g2->drawStr(0, 8, "Time's up");
+    } else if (this->running) {
+        u8g2->drawStr(0, 8, "Running");
+    } else if (this->remaining > 0) {
+        u8g2->drawStr(0, 8, "Paused");
+    }
+
+    if (this->running || this->remaining > 0) {
+        format_clock(text, sizeof(text), (this->left(ms) + 999) / 1000);
+    } else {
+        // the digits as typed, which can be more than 59 seconds
+        format_int(text, sizeof(text), this->entered / 100, 2, '0');
+        text[2] = ':';
+        format_int(text + 3, 3, this->entered % 100, 2, '0');
+    }
+
+    u8g2->setFont(u8g2_font_logisoso22_tn);
+    u8g2->drawStr(127 - u8g2->getStrWidth(text), 31, text);
+
+    u8g2->setDrawColor(1);
+    u8g2->sendBuffer();
+}
+
+void Countdown::tick(const unsigned long ms) {
+    if (this->running && this->left(ms) == 0) {
+        this->running = false;
+        this->remaining = 0;
+        this->ringing = true;
+    }
+
+    // once a second while counting, faster to flash
+    const unsigned long step = this->ringing ? ms / COUNTDOWN_FLASH_MS : (this->left(ms) + 999) / 1000;
+
+    if (step != this->shownStep) {
+        this->shownStep = step;
+        this->drawNext = true;
+    }
+}
+
+void Countdown::onShow() {
+    this->drawNext = true;
+}
+
+bool Countdown::describeKey(char row, char column, char* label) const {
+    const unsigned char input = this->getChar(row, column);
+
+    switch (input) {
+        case 0: return false;
+        case 'C': strcpy_P(label, PSTR("Clear")); break;
+        case '\n': strcpy_P(label, PSTR("Start")); break;
+        default:
+            label[0] = input;
+            label[1] = 0;
+            break;
+    }
+
+    return true;
+}
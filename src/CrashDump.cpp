@@ -0,0 +1,88 @@
+#include "CrashDump.hpp"
+#include "EepromLayout.h"
+#include "WallTime.hpp"
+#include "Reset.hpp"
+#include <EEPROM.h>
+#include <avr/interrupt.h>
+#include <avr/io.h>
+#include <avr/wdt.h>
+
+typedef union {
+    CrashRecord record;
+    unsigned char bytes[sizeof(CrashRecord)];
+} CrashSlot;
+
+void crashdump_begin() {
+    WDTCSR |= _BV(WDIE);
+}
+
+static void __attribute__((noinline, used)) crashdump_save(const unsigned short pc, const unsigned short sp) {
+    CrashSlot slot;
+    slot.record.magic = CRASH_DUMP_MAGIC;
+    slot.record.pc = pc;
+    slot.record.sp = sp;
+    slot.record.uptime = millis();
+    slot.record.time = walltime_now();
+
+    for (unsigned char i = 0; i < sizeof(CrashRecord); i++) {
+        EEPROM.update(ADDR_EEPROM_CRASH_DUMP + i, slot.bytes[i]);
+    }
+
+    reset_mark(RESET_MARK_WATCHDOG);
+
+    // reset right away instead of waiting for another timeout
+    wdt_enable(WDTO_15MS);
+    while (true);
+}
+
+// Naked so SP still points just below the return address of whatever got
+// interrupted. The board resets afterwards, so nothing needs preserving.
+ISR(WDT_vect, ISR_NAKED) {
+    asm volatile("clr r1");
+
+    const unsigned char* stack = (const unsigned char*)SP;
+    const unsigned short pc = ((stack[1] << 8) | stack[2]) << 1;
+
+    crashdump_save(pc, SP + 2);
+}
+
+bool crashdump_load(CrashRecord* record) {
+    CrashSlot slot;
+
+    for (unsigned char i = 0; i < sizeof(CrashRecord); i++) {
+        slot.bytes[i] = EEPROM.read(ADDR_EEPROM_CRASH_DUMP + i);
+    }
+
+    if (slot.record.magic != CRASH_DUMP_MAGIC) {
+        return false;
+    }
+
+    *record = slot.record;
+    return true;
+}
+
+void crashdump_clear() {
+    EEPROM.update(ADDR_EEPROM_CRASH_DUMP, 0xFF);
+}
+
+void crashdump_print(Print* out) {
+    CrashRecord record;
+
+    if (!crashdump_load(&record)) {
+        out->println(F("no crash recorded"));
+        return;
+    }
+
+    out->print(F("watchdog reset at pc=0x")); out->print(record.pc, HEX);
+    out->print(F(" sp=0x")); out->print(record.sp, HEX);
+    out->print(F(" uptime=")); out->print(record.uptime, DEC);
+    out->print(F("ms"));
+
+    if (record.time != 0) {
+        char text[20];
+        out->print(F(" at "));
+        out->print(walltime_format(text, sizeof(text), record.time));
+    }
+
+    out->println();
+}
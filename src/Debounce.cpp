@@ -0,0 +1,43 @@
+#include "Debounce.hpp"
+
+bool Debounce::update(const unsigned char key, const bool raw, const unsigned long time) {
+    KeyState* state = &this->keys[key];
+    const unsigned char now = time;
+
+#if DEBOUNCE_ALGORITHM == DEBOUNCE_EAGER
+    // the first edge goes through right away, bounces after it don't
+    if (raw != state->pressed && (unsigned char)(now - state->since) >= DEBOUNCE_MS) {
+        state->pressed = raw;
+        state->since = now;
+    }
+#elif DEBOUNCE_ALGORITHM == DEBOUNCE_DEFERRED
+    if (raw != state->raw) {
+        state->raw = raw;
+        state->since = now;
+    } else if (raw != state->pressed && (unsigned char)(now - state->since) >= DEBOUNCE_MS) {
+        state->pressed = raw;
+    }
+#elif DEBOUNCE_ALGORITHM == DEBOUNCE_INTEGRATOR
+    if (raw && state->since < DEBOUNCE_SAMPLES) {
+        state->since++;
+    } else if (!raw && state->since > 0) {
+        state->since--;
+    }
+
+    if (state->since == DEBOUNCE_SAMPLES) {
+        state->pressed = true;
+    } else if (state->since == 0) {
+        state->pressed = false;
+    }
+#else
+#error "Unknown DEBOUNCE_ALGORITHM"
+#endif
+
+    return state->pressed;
+}
+
+void Debounce::hold(const unsigned char key) {
+    this->keys[key].pressed = true;
+    this->keys[key].raw = true;
+    this->keys[key].since = DEBOUNCE_ALGORITHM == DEBOUNCE_INTEGRATOR ? DEBOUNCE_SAMPLES : 0;
+}
@@ -0,0 +1,33 @@
+#include "Diagnostics.hpp"
+#include "Format.hpp"
+#include "Memory.hpp"
+
+void Diagnostics::draw(U8G2* u8g2) {
+    if (!this->drawNext) return;
+    this->drawNext = false;
+
+    char value[8];
+
+    u8g2->clearBuffer();
+    u8g2->setFont(u8g2_font_ncenB08_tr);
+
+    u8g2->drawStr(0, 10, "Static");
+    u8g2->drawStr(60, 10, format_int(value, sizeof(value), memory_static()));
+    u8g2->drawStr(0, 21, "Free");
+    u8g2->drawStr(60, 21, format_int(value, sizeof(value), memory_free()));
+    u8g2->drawStr(0, 32, "Headroom");
+    u8g2->drawStr(60, 32, format_int(value, sizeof(value), memory_stack_headroom()));
+
+    u8g2->sendBuffer();
+}
+
+void Diagnostics::tick(const unsigned long ms) {
+    if (ms - this->lastUpdate >= 1000) {
+        this->lastUpdate = ms;
+        this->drawNext = true;
+    }
+}
+
+void Diagnostics::onShow() {
+    this->drawNext = true;
+}
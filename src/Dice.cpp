@@ -0,0 +1,116 @@
+#include "Dice.hpp"
+#include "Format.hpp"
+#include "Arduino.h"
+#include "Features.h"
+
+#if FEATURE_DICE
+unsigned char Dice::getSides(unsigned char row, unsigned char column) const {
+    return this->mapping[row][column];
+}
+
+void Dice::roll(const unsigned char sides) {
+    // when a person presses a key is the only entropy there is
+    this->seed = this->seed * 31 + micros();
+    randomSeed(this->seed);
+
+    const unsigned long ms = millis();
+
+    this->sides = sides;
+    this->result = random(sides) + 1;
+    this->rolling = true;
+    this->rollEnds = ms + DICE_ROLL_MS;
+    this->lastFrame = ms;
+    this->drawNext = true;
+}
+
+void Dice::onPress(char row, char column) {
+    const unsigned char sides = this->getSides(row, column);
+
+    if (sides == 12) {
+        this->longPressed = false;
+    } else if (sides > 0) {
+        this->roll(sides);
+    }
+}
+
+void Dice::onRelease(char row, char column) {
+    if (this->getSides(row, column) == 12 && !this->longPressed) {
+        this->roll(12);
+    }
+}
+
+void Dice::onLongPress(char row, char column) {
+    if (this->getSides(row, column) == 12 && !this->longPressed) {
+        this->longPressed = true;
+        this->roll(20);
+    }
+}
+
+void Dice::draw(U8G2* u8g2) {
+    if (!this->drawNext) return;
+    this->drawNext = false;
+
+    char text[6];
+
+    u8g2->clearBuffer();
+    u8g2->setFont(u8g2_font_ncenB08_tr);
+
+    if (this->sides == 0) {
+        u8g2->drawStr(0, 10, "Pick a die");
+        u8g2->setFont(u8g2_font_5x8_tr);
+        u8g2->drawStr(0, 24, "4 6 8, 0 is d10");
+        u8g2->drawStr(0, 32, "2 is d12, hold for d20");
+        u8g2->sendBuffer();
+        return;
+    }
+
+    text[0] = 'd';
+    format_int(text + 1, sizeof(text) - 1, this->sides);
+    u8g2->drawStr(0, 10, text);
+
+    u8g2->setFont(u8g2_font_logisoso22_tn);
+    format_int(text, sizeof(text), this->shownFace);
+    u8g2->drawStr(64 - u8g2->getStrWidth(text) / 2, 28, text);
+
+    u8g2->sendBuffer();
+}
+
+void Dice::tick(const unsigned long ms) {
+    if (!this->rolling) {
+        return;
+    }
+
+    if ((long)(ms - this->rollEnds) >= 0) {
+        this->rolling = false;
+        this->shownFace = this->result;
+        this->drawNext = true;
+    } else if (ms - this->lastFrame >= DICE_FRAME_MS) {
+        // faces flicking past, only for show
+        this->lastFrame = ms;
+        this->shownFace = random(this->sides) + 1;
+        this->drawNext = true;
+    }
+}
+
+void Dice::onShow() {
+    this->drawNext = true;
+}
+
+bool Dice::describeKey(char row, char column, char* label) const {
+    const unsigned char sides = this->getSides(row, column);
+
+    if (sides == 0) {
+        return false;
+    }
+
+    if (sides == 12) {
+        strcpy_P(label, PSTR("d12/20"));
+    } else {
+        label[0] = 'd';
+        format_int(label + 1, HELP_LABEL_SIZE - 1, sides);
+    }
+
+    return true;
+}
+
+#endif
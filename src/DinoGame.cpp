@@ -1,5 +1,7 @@
 #include "DinoGraphics.h"
 #include "DinoGame.hpp"
+#include "Format.hpp"
+#include "Log.hpp"
 
 unsigned char DinoGame::getAction(unsigned char row, unsigned char column)  const {
     return this->mapping[row][column];
@@ -58,7 +60,7 @@ void DinoGame::draw(U8G2* u8g2) {
     this->dino.draw(u8g2);
 
     char scoreText[12];
-    String(this->score).toCharArray(scoreText, sizeof(scoreText));
+    format_int(scoreText, sizeof(scoreText), this->score);
 
     u8g2->setFont(u8g2_font_baby_tn);
     u8g2->drawStr(0, 5, scoreText);
@@ -158,5 +160,5 @@ void DinoGame::spawnMob() {
 
     this->entities[id] = Entity(128, 0, Animation(newFrames, 2, 400));
 
-    Serial.println("SPAWN");
+    Log.println(F("SPAWN"));
 }
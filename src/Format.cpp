@@ -0,0 +1,199 @@
+#include "Format.hpp"
+#include "Arduino.h"
+#include <math.h>
+
+char* format_int(char* buffer, const unsigned char size, long value, const unsigned char width, const char pad) {
+    char digits[12];
+    ltoa(value, digits, 10);
+
+    strncpy(buffer, digits, size - 1);
+    buffer[size - 1] = 0;
+
+    return pad_left(buffer, size, width, pad);
+}
+
+char* format_double(char* buffer, const unsigned char size, double value, const unsigned char precision) {
+    // past this many integer digits the scratch buffer would overflow, and
+    // nothing that long fits a buffer anyway
+    if (fabs(value) >= 1e15) {
+        return format_exponent(buffer, size, value);
+    }
+
+    // same scratch size String uses for doubles
+    char digits[33];
+    dtostrf(value, precision + 2, precision, digits);
+
+    if (precision > 0 && strchr(digits, '.') != nullptr) {
+        // trim zeros
+        char* back = digits + strlen(digits);
+        while((*--back) == '0');
+        if((*back) == '.') back--;
+        *(back+1) = '\0';
+    }
+
+    // anything that rounded away to nothing
+    if (strcmp(digits, "-0") == 0) {
+        strcpy(digits, "0");
+    }
+
+    // cutting digits off would show a different number
+    if (strlen(digits) >= size) {
+        return format_exponent(buffer, size, value);
+    }
+
+    strcpy(buffer, digits);
+
+    return buffer;
+}
+
+char* format_exponent(char* buffer, const unsigned char size, double value) {
+    // sign, one digit, the point and e+XX leave the rest to decimals, single
+    // precision has no more than 6 that mean anything
+    const unsigned char fixed = (value < 0 ? 1 : 0) + 7;
+    const unsigned char precision = size > fixed ? min(size - fixed, 6) : 0;
+
+    char digits[16];
+    dtostre(value, digits, precision, 0);
+
+    // trim zeros of the mantissa, 1.5000e+12 to 1.5e+12
+    char* exponent = strchr(digits, 'e');
+
+    if (exponent != nullptr && precision > 0) {
+        char* back = exponent;
+        while((*--back) == '0');
+        if((*back) == '.') back--;
+        memmove(back + 1, exponent, strlen(exponent) + 1);
+    }
+
+    strncpy(buffer, digits, size - 1);
+    buffer[size - 1] = 0;
+
+    return buffer;
+}
+
+bool format_fraction(char* buffer, const unsigned char size, double value, const long maxDenominator) {
+    const double target = fabs(value);
+
+    // out of reach of long, and of float's fraction digits well before that
+    if (target >= 1e6) {
+        return false;
+    }
+
+    // continued fraction convergents, h / k gets closer with every term
+    long h0 = 0, h1 = 1;
+    long k0 = 1, k1 = 0;
+    double rest = target;
+
+    for (unsigned char i = 0; i < 16; i++) {
+        const long term = floor(rest);
+        const long h2 = term * h1 + h0;
+        const long k2 = term * k1 + k0;
+
+        if (k2 > maxDenominator) {
+            return false;
+        }
+
+        h0 = h1; h1 = h2;
+        k0 = k1; k1 = k2;
+
+        if (fabs(target - (double)h1 / k1) <= target * 1e-6) {
+            break;
+        }
+
+        if (rest - term < 1e-9) {
+            return false;
+        }
+
+        rest = 1 / (rest - term);
+    }
+
+    if (k1 <= 1 || fabs(target - (double)h1 / k1) > target * 1e-6) {
+        return false;
+    }
+
+    char digits[12];
+
+    buffer[0] = 0;
+
+    if (value < 0) {
+        strcat(buffer, "-");
+    }
+
+    ltoa(h1, digits, 10);
+
+    if (strlen(buffer) + strlen(digits) + 1 >= size) {
+        return false;
+    }
+
+    strcat(buffer, digits);
+    strcat(buffer, "/");
+    ltoa(k1, digits, 10);
+
+    if (strlen(buffer) + strlen(digits) >= size) {
+        return false;
+    }
+
+    strcat(buffer, digits);
+
+    return true;
+}
+
+char* format_unit(char* buffer, const unsigned char size, long value, const char* suffix) {
+    format_int(buffer, size, value);
+
+    const unsigned char length = strlen(buffer);
+    strncpy(buffer + length, suffix, size - length - 1);
+    buffer[size - 1] = 0;
+
+    return buffer;
+}
+
+char* format_clock(char* buffer, const unsigned char size, unsigned long seconds) {
+    format_int(buffer, size, seconds / 60, 2, '0');
+
+    const unsigned char length = strlen(buffer);
+
+    if (length + 3 < size) {
+        buffer[length] = ':';
+        format_int(buffer + length + 1, 3, seconds % 60, 2, '0');
+    }
+
+    return buffer;
+}
+
+char* format_group(char* buffer, const unsigned char size, const char separator) {
+    const unsigned char start = buffer[0] == '-' ? 1 : 0;
+    unsigned char end = start;
+
+    while (buffer[end] >= '0' && buffer[end] <= '9') end++;
+
+    // the whole shifts right as separators go in from the back
+    for (unsigned char digits = end - start; digits > 3; digits -= 3) {
+        const unsigned char at = start + digits - 3;
+        const unsigned char length = strlen(buffer);
+
+        if (length + 1 >= size) {
+            break;
+        }
+
+        memmove(buffer + at + 1, buffer + at, length - at + 1);
+        buffer[at] = separator;
+    }
+
+    return buffer;
+}
+
+char* pad_left(char* buffer, const unsigned char size, const unsigned char width, const char pad) {
+    const unsigned char length = strlen(buffer);
+
+    if (length >= width || width >= size) {
+        return buffer;
+    }
+
+    const unsigned char shift = width - length;
+
+    memmove(buffer + shift, buffer, length + 1);
+    memset(buffer, pad, shift);
+
+    return buffer;
+}
@@ -0,0 +1,102 @@
+#include "HeldKeys.hpp"
+
+HeldKey* HeldKeys::find(const unsigned char key) {
+    for (unsigned char i = 0; i < this->count; i++) {
+        if (this->keys[i].key == key && this->keys[i].down) {
+            return &this->keys[i];
+        }
+    }
+
+    return nullptr;
+}
+
+void HeldKeys::remove(const unsigned char index) {
+    memmove(this->keys + index, this->keys + index + 1, (this->count - index - 1) * sizeof(HeldKey));
+    this->count--;
+}
+
+void HeldKeys::press(const unsigned char key, const char input, const bool waits) {
+    if (this->count == HELD_KEYS_SIZE) {
+        // more keys down than anyone can press on purpose
+        this->remove(0);
+    }
+
+    HeldKey* entry = &this->keys[this->count++];
+
+    entry->key = key;
+    entry->input = input;
+    entry->down = waits;
+    entry->done = false;
+}
+
+bool HeldKeys::release(const unsigned char key) {
+    HeldKey* entry = this->find(key);
+
+    if (entry == nullptr) {
+        return false;
+    }
+
+    entry->down = false;
+    return true;
+}
+
+bool HeldKeys::longPress(const unsigned char key) {
+    HeldKey* entry = this->find(key);
+
+    if (entry == nullptr || entry->done) {
+        return false;
+    }
+
+    entry->done = true;
+    return true;
+}
+
+char HeldKeys::held() const {
+    for (unsigned char i = this->count; i > 0; i--) {
+        if (this->keys[i - 1].down) {
+            return this->keys[i - 1].input;
+        }
+    }
+
+    return 0;
+}
+
+void HeldKeys::chord() {
+    for (unsigned char i = this->count; i > 0; i--) {
+        if (this->keys[i - 1].down) {
+            this->keys[i - 1].done = true;
+            return;
+        }
+    }
+}
+
+char HeldKeys::next() {
+    unsigned char i = 0;
+
+    while (i < this->count) {
+        const HeldKey entry = this->keys[i];
+
+        if (entry.down && !entry.done) {
+            // everything after has to wait for it
+            return 0;
+        }
+
+        if (entry.down) {
+            // long pressed and still held, nothing to wait for
+            i++;
+            continue;
+        }
+
+        this->remove(i);
+
+        if (!entry.done) {
+            return entry.input;
+        }
+    }
+
+    return 0;
+}
+
+void HeldKeys::clear() {
+    this->count = 0;
+}
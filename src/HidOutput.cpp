@@ -0,0 +1,202 @@
+#include "HidOutput.hpp"
+#include "Macros.hpp"
+#include "TestHooks.hpp"
+
+HidOutput hid;
+
+void HidOutput::block(const EHidBlock reason) {
+    this->stopTyping();
+    this->releaseAll();
+    this->blocks |= reason;
+}
+
+void HidOutput::unblock(const EHidBlock reason) {
+    this->blocks &= ~reason;
+}
+
+bool HidOutput::isBlocked(const EHidBlock reason) const {
+    return this->blocks & reason;
+}
+
+bool HidOutput::isEnabled() const {
+    return this->blocks == 0;
+}
+
+void HidOutput::press(const KeyboardKeycode key, const bool synthetic) {
+    if (!this->isEnabled()) {
+        return;
+    }
+
+    // a key wakes a sleeping host instead of getting lost
+    if (USBDevice.isSuspended()) {
+        USBDevice.wakeupHost();
+    }
+
+    if (!synthetic) {
+        macros.record(MACRO_PRESS, key);
+    }
+
+    TEST_REPORT(key, true, false);
+    BootKeyboard.press(key);
+}
+
+void HidOutput::release(const KeyboardKeycode key, const bool synthetic) {
+    if (!this->isEnabled()) {
+        return;
+    }
+
+    if (!synthetic) {
+        macros.record(MACRO_RELEASE, key);
+    }
+
+    TEST_REPORT(key, false, false);
+    BootKeyboard.release(key);
+}
+
+void HidOutput::releaseAll() {
+    BootKeyboard.releaseAll();
+#if HID_MOUSE
+    Mouse.releaseAll();
+#endif
+#if HID_CONSUMER
+    Consumer.releaseAll();
+#endif
+}
+
+void HidOutput::queueCharacter(const char character) {
+    if (!this->isEnabled() || this->count >= HID_TYPE_QUEUE_SIZE) {
+        return;
+    }
+
+    this->queue[(this->head + this->count) % HID_TYPE_QUEUE_SIZE] = character;
+    this->count++;
+}
+
+void HidOutput::write(const char character) {
+    this->queueCharacter(character & 0x7F);
+}
+
+void HidOutput::print(const char* text) {
+    for (; *text != 0; text++) {
+        this->write(*text);
+    }
+}
+
+void HidOutput::printKeypad(const char* number) {
+    for (; *number != 0; number++) {
+        switch (*number) {
+            case '0': case '1': case '2': case '3': case '4':
+            case '5': case '6': case '7': case '8': case '9':
+            case '.': case '-': case '+':
+                this->queueCharacter(*number | 0x80);
+                break;
+            default:
+                this->write(*number);
+                break;
+        }
+    }
+}
+
+bool HidOutput::isTyping() const {
+    return this->count > 0;
+}
+
+void HidOutput::stopTyping() {
+    this->count = 0;
+}
+
+void HidOutput::type(const char character) {
+    if (!(character & 0x80)) {
+        if (this->isEnabled()) {
+            TEST_REPORT(character, true, true);
+            BootKeyboard.write(character);
+        }
+
+        return;
+    }
+
+    KeyboardKeycode key;
+
+    switch (character & 0x7F) {
+        case '0': key = KEYPAD_0; break;
+        case '.': key = KEYPAD_DOT; break;
+        case '-': key = KEYPAD_SUBTRACT; break;
+        case '+': key = KEYPAD_ADD; break;
+        default: key = (KeyboardKeycode)(KEYPAD_1 + (character & 0x7F) - '1'); break;
+    }
+
+    this->press(key, true);
+    this->release(key, true);
+}
+
+void HidOutput::tick(const unsigned long ms) {
+    if (this->count == 0 || ms - this->lastType < HID_TYPE_INTERVAL) {
+        return;
+    }
+
+    const char character = this->queue[this->head];
+
+    this->head = (this->head + 1) % HID_TYPE_QUEUE_SIZE;
+    this->count--;
+    this->lastType = ms;
+
+    this->type(character);
+}
+
+void HidOutput::system(const SystemKeycode key) {
+    if (!this->isEnabled()) {
+        return;
+    }
+
+    System.write(key);
+}
+
+void HidOutput::consumerPress(const ConsumerKeycode key) {
+    if (!this->isEnabled()) {
+        return;
+    }
+
+#if HID_CONSUMER
+    Consumer.press(key);
+#endif
+}
+
+void HidOutput::consumerRelease(const ConsumerKeycode key) {
+    if (!this->isEnabled()) {
+        return;
+    }
+
+#if HID_CONSUMER
+    Consumer.release(key);
+#endif
+}
+
+void HidOutput::mouseMove(const signed char x, const signed char y, const signed char wheel) {
+    if (!this->isEnabled()) {
+        return;
+    }
+
+#if HID_MOUSE
+    Mouse.move(x, y, wheel);
+#endif
+}
+
+void HidOutput::mousePress(const uint8_t buttons) {
+    if (!this->isEnabled()) {
+        return;
+    }
+
+#if HID_MOUSE
+    Mouse.press(buttons);
+#endif
+}
+
+void HidOutput::mouseRelease(const uint8_t buttons) {
+    if (!this->isEnabled()) {
+        return;
+    }
+
+#if HID_MOUSE
+    Mouse.release(buttons);
+#endif
+}
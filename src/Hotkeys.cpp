@@ -0,0 +1,204 @@
+#include "Hotkeys.hpp"
+#include "Format.hpp"
+
+#ifdef HOTKEY_CONFIG
+#include HOTKEY_CONFIG
+#else
+#include "HotkeyConfig.h"
+#endif
+
+#define HOTKEY_COUNT (sizeof(hotkeyTable) / sizeof(hotkeyTable[0]))
+#define HOTKEY_INACTIVE 0xFF
+
+// in flash, for strcpy_P
+const char* hotkeyName(const EHotkeyAction action) {
+    switch (action) {
+        case HOTKEY_NEXT_MODE:
+            return PSTR("Next mode");
+        case HOTKEY_GAME_MODE:
+            return PSTR("Dino");
+        case HOTKEY_LOCK_HOST:
+            return PSTR("Lock PC");
+        case HOTKEY_REBOOT:
+            return PSTR("Reboot");
+        case HOTKEY_MACRO_RECORD:
+            return PSTR("Record");
+        case HOTKEY_DOUBLE_ZERO:
+            return PSTR("00");
+        case HOTKEY_TRIPLE_ZERO:
+            return PSTR("000");
+        case HOTKEY_KILL_SWITCH:
+            return PSTR("Kill switch");
+        case HOTKEY_SLEEP_HOST:
+            return PSTR("Sleep PC");
+        case HOTKEY_TIMESTAMP:
+            return PSTR("Timestamp");
+        case HOTKEY_HELP:
+            return PSTR("Help");
+        case HOTKEY_LOCK_KEYPAD:
+            return PSTR("Lock");
+        default:
+            return action >= HOTKEY_MACRO_1 ? PSTR("Macro") : PSTR("");
+    }
+}
+
+Hotkeys::Hotkeys() {
+    this->active = HOTKEY_INACTIVE;
+    this->fired = false;
+}
+
+void Hotkeys::load(const unsigned char index, Hotkey* hotkey) const {
+    memcpy_P(hotkey, &hotkeyTable[index], sizeof(Hotkey));
+}
+
+void Hotkeys::setTapChordsEnabled(const bool enabled) {
+    this->tapChordsEnabled = enabled;
+}
+
+bool Hotkeys::isEnabled(const Hotkey* hotkey) const {
+    // a held chord can't be hit by rolling from one key onto the next
+    return this->tapChordsEnabled || hotkey->holdMs > 0;
+}
+
+void Hotkeys::ignoreUntilReleased(const unsigned char key) {
+    this->ignored |= 1UL << key;
+}
+
+bool Hotkeys::isHeld(const Hotkey* hotkey, const unsigned long states[COLS][ROWS]) const {
+    if (this->ignored & this->mask(hotkey)) {
+        return false;
+    }
+
+    for (unsigned char i = 0; i < HOTKEY_MAX_KEYS && hotkey->keys[i] != NO_KEY; i++) {
+        const unsigned char key = hotkey->keys[i];
+
+        if (states[key % COLS][key / COLS] == 0) {
+            return false;
+        }
+    }
+
+    return true;
+}
+
+unsigned long Hotkeys::mask(const Hotkey* hotkey) const {
+    unsigned long bits = 0;
+
+    for (unsigned char i = 0; i < HOTKEY_MAX_KEYS && hotkey->keys[i] != NO_KEY; i++) {
+        bits |= 1UL << hotkey->keys[i];
+    }
+
+    return bits;
+}
+
+bool Hotkeys::onPress(const unsigned char row, const unsigned char column, const unsigned long states[COLS][ROWS]) {
+    const unsigned long bit = 1UL << KEY_ID(row, column);
+    Hotkey hotkey;
+
+    for (unsigned char i = 0; i < HOTKEY_COUNT; i++) {
+        this->load(i, &hotkey);
+
+        if ((this->mask(&hotkey) & bit) && this->isEnabled(&hotkey) && this->isHeld(&hotkey, states)) {
+            this->pending |= bit;
+            return false;
+        }
+    }
+
+    return true;
+}
+
+EHotkeyRelease Hotkeys::onRelease(const unsigned char row, const unsigned char column) {
+    const unsigned long bit = 1UL << KEY_ID(row, column);
+    EHotkeyRelease result = HOTKEY_PASS;
+
+    if (this->consumed & bit || this->ignored & bit) {
+        // the mode never saw the press either
+        result = HOTKEY_SWALLOW;
+    } else if (this->pending & bit) {
+        result = HOTKEY_TAP;
+    }
+
+    this->pending &= ~bit;
+    this->consumed &= ~bit;
+    this->ignored &= ~bit;
+
+    return result;
+}
+
+EHotkeyAction Hotkeys::update(const unsigned long states[COLS][ROWS], const unsigned long time) {
+    Hotkey hotkey;
+    unsigned char found = HOTKEY_INACTIVE;
+
+    for (unsigned char i = 0; i < HOTKEY_COUNT; i++) {
+        this->load(i, &hotkey);
+
+        if (this->isEnabled(&hotkey) && this->isHeld(&hotkey, states)) {
+            found = i;
+            break;
+        }
+    }
+
+    if (found != this->active) {
+        this->active = found;
+        this->activeSince = time;
+        this->fired = false;
+    }
+
+    if (found == HOTKEY_INACTIVE || this->fired || time - this->activeSince < hotkey.holdMs) {
+        return HOTKEY_NONE;
+    }
+
+    this->fired = true;
+    this->consumed |= this->pending & this->mask(&hotkey);
+
+    return hotkey.action;
+}
+
+bool Hotkeys::isActive(const unsigned char row, const unsigned char column) const {
+    if (this->active == HOTKEY_INACTIVE) {
+        return false;
+    }
+
+    Hotkey hotkey;
+    this->load(this->active, &hotkey);
+
+    for (unsigned char i = 0; i < HOTKEY_MAX_KEYS && hotkey.keys[i] != NO_KEY; i++) {
+        if (hotkey.keys[i] == KEY_ID(row, column)) {
+            return true;
+        }
+    }
+
+    return false;
+}
+
+bool Hotkeys::draw(U8G2* u8g2, const unsigned long time) const {
+    if (this->active == HOTKEY_INACTIVE || this->fired) {
+        return false;
+    }
+
+    Hotkey hotkey;
+    this->load(this->active, &hotkey);
+
+    const unsigned long held = time - this->activeSince;
+
+    if (hotkey.holdMs <= HOTKEY_FEEDBACK_DELAY || held < HOTKEY_FEEDBACK_DELAY) {
+        return false;
+    }
+
+    const unsigned long remaining = hotkey.holdMs - held;
+
+    char text[20];
+    strcpy_P(text, hotkeyName(hotkey.action));
+    strcat_P(text, PSTR(" in "));
+    format_int(text + strlen(text), sizeof(text) - strlen(text), remaining / 1000 + 1);
+
+    u8g2->setFont(u8g2_font_ncenB08_tr);
+
+    u8g2->setDrawColor(0);
+    u8g2->drawBox(8, 4, 112, 24);
+    u8g2->setDrawColor(1);
+    u8g2->drawFrame(8, 4, 112, 24);
+    u8g2->drawStr(64 - u8g2->getStrWidth(text) / 2, 16, text);
+    u8g2->drawBox(12, 21, (104 * held) / hotkey.holdMs, 3);
+
+    return true;
+}
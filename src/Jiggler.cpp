@@ -0,0 +1,98 @@
+#include "Jiggler.hpp"
+#include "HidOutput.hpp"
+#include "Format.hpp"
+
+// seconds between nudges that plus and minus step through
+const unsigned short jigglerIntervals[] = {10, 30, 60, 120, 300, 600};
+
+#define JIGGLER_INTERVAL_COUNT (sizeof(jigglerIntervals) / sizeof(jigglerIntervals[0]))
+
+unsigned char Jiggler::getChar(unsigned char row, unsigned char column) const {
+    return this->mapping[row][column];
+}
+
+void Jiggler::onPress(char row, char column) {
+    unsigned char i = 0;
+
+    this->drawNext = true;
+
+    switch (this->getChar(row, column)) {
+        case '+':
+            while (i < JIGGLER_INTERVAL_COUNT - 1 && jigglerIntervals[i] <= this->interval) i++;
+            this->interval = max(this->interval, jigglerIntervals[i]);
+            break;
+        case '-':
+            i = JIGGLER_INTERVAL_COUNT - 1;
+            while (i > 0 && jigglerIntervals[i] >= this->interval) i--;
+            this->interval = min(this->interval, jigglerIntervals[i]);
+            break;
+        case '\n':
+            this->running = !this->running;
+            this->lastNudge = millis();
+            break;
+    }
+}
+
+void Jiggler::draw(U8G2* u8g2) {
+    if (!this->drawNext) return;
+    this->drawNext = false;
+
+    char value[12];
+    const unsigned long waited = (millis() - this->lastNudge) / 1000;
+
+    u8g2->clearBuffer();
+    u8g2->setFont(u8g2_font_ncenB08_tr);
+
+    u8g2->drawStr(0, 10, this->running ? "Jiggling" : "Paused");
+    u8g2->drawStr(70, 10, format_unit(value, sizeof(value), this->interval, "s"));
+
+    u8g2->setFont(u8g2_font_5x8_tr);
+    u8g2->drawStr(0, 21, "Nudged");
+    u8g2->drawStr(40, 21, format_int(value, sizeof(value), this->nudges));
+
+    if (this->running) {
+        u8g2->drawStr(0, 31, "Next in");
+        u8g2->drawStr(40, 31, format_clock(value, sizeof(value), this->interval - min(waited, (unsigned long)this->interval)));
+    }
+
+    // a pointer that hops over with every nudge
+    const unsigned char x = this->nudges % 2 == 0 ? 110 : 113;
+
+    u8g2->drawTriangle(x, 16, x, 28, x + 8, 24);
+
+    u8g2->sendBuffer();
+}
+
+void Jiggler::tick(const unsigned long ms) {
+    if (this->running && ms - this->lastNudge >= this->interval * 1000UL) {
+        this->lastNudge = ms;
+        this->nudges++;
+
+        // there and back, the pointer ends up where it was
+        hid.mouseMove(1, 0);
+        hid.mouseMove(-1, 0);
+    }
+
+    const unsigned long second = (ms - this->lastNudge) / 1000;
+
+    if (second != this->shownSecond) {
+        this->shownSecond = second;
+        this->drawNext = true;
+    }
+}
+
+void Jiggler::onShow() {
+    this->drawNext = true;
+    this->lastNudge = millis();
+}
+
+bool Jiggler::describeKey(char row, char column, char* label) const {
+    switch (this->getChar(row, column)) {
+        case '+': strcpy_P(label, PSTR("Slower")); break;
+        case '-': strcpy_P(label, PSTR("Faster")); break;
+        case '\n': strcpy_P(label, PSTR("Pause")); break;
+        default: return false;
+    }
+
+    return true;
+}
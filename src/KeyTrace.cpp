@@ -0,0 +1,54 @@
+#include "KeyTrace.hpp"
+#include "KeyboardConfig.h"
+
+#if FEATURE_TRACE
+KeyTrace keyTrace;
+#endif
+
+#define TRACE_DEBOUNCED 1 // otherwise a raw reading
+#define TRACE_DOWN 2
+
+void KeyTrace::add(const unsigned char key, const unsigned char flags, const unsigned long time) {
+    this->entries[this->next].time = time;
+    this->entries[this->next].key = key;
+    this->entries[this->next].flags = flags;
+
+    this->next = (this->next + 1) % KEY_TRACE_SIZE;
+
+    if (this->count < KEY_TRACE_SIZE) {
+        this->count++;
+    }
+}
+
+void KeyTrace::update(const unsigned char key, const bool raw, const bool debounced, const unsigned long time) {
+    const unsigned long bit = 1UL << key;
+
+    if (raw != ((this->raw & bit) != 0)) {
+        this->raw ^= bit;
+        this->add(key, raw ? TRACE_DOWN : 0, time);
+    }
+
+    if (debounced != ((this->debounced & bit) != 0)) {
+        this->debounced ^= bit;
+        this->add(key, TRACE_DEBOUNCED | (debounced ? TRACE_DOWN : 0), time);
+    }
+}
+
+void KeyTrace::print(Print* out) const {
+    out->println(F("ms,row,col,kind,state"));
+
+    for (unsigned char i = 0; i < this->count; i++) {
+        const KeyTraceEntry* entry = &this->entries[(this->next + KEY_TRACE_SIZE - this->count + i) % KEY_TRACE_SIZE];
+
+        out->print(entry->time, DEC); out->print(',');
+        out->print(entry->key / COLS, DEC); out->print(',');
+        out->print(entry->key % COLS, DEC); out->print(',');
+        out->print(entry->flags & TRACE_DEBOUNCED ? F("debounced,") : F("raw,"));
+        out->println(entry->flags & TRACE_DOWN ? F("down") : F("up"));
+    }
+}
+
+void KeyTrace::clear() {
+    this->count = 0;
+    this->next = 0;
+}
@@ -0,0 +1,31 @@
+#include "LiveNumpad.hpp"
+#include "Hotkeys.hpp"
+
+LiveNumpad::LiveNumpad(Calculator* calculator) {
+    this->calculator = calculator;
+}
+
+void LiveNumpad::onPress(char row, char column) {
+    Numpad::onPress(row, column);
+
+    // the top row are host keys and memory slots on the calculator, and num
+    // lock toggles the host's num lock rather than clearing. The host got
+    // the key on press, so the calculator takes it then too.
+    if (row > 0 && KEY_ID(row, column) != KEY_ID(1, 0)) {
+        this->calculator->tap(row, column);
+        this->drawNext = true;
+    }
+}
+
+void LiveNumpad::draw(U8G2* u8g2) {
+    if (!this->drawNext) return;
+    this->drawNext = false;
+
+    this->calculator->invalidate();
+    this->calculator->draw(u8g2);
+}
+
+void LiveNumpad::onShow() {
+    Numpad::onShow();
+    this->calculator->onShow();
+}
@@ -0,0 +1,50 @@
+#include "Log.hpp"
+#include "WallTime.hpp"
+
+LogQueue Log;
+
+size_t LogQueue::push(uint8_t value) {
+    if (this->count >= LOG_QUEUE_SIZE) {
+        return 0;
+    }
+
+    this->buffer[(this->head + this->count) % LOG_QUEUE_SIZE] = value;
+    this->count++;
+
+    return 1;
+}
+
+size_t LogQueue::write(uint8_t value) {
+    if (this->lineStart && walltime_is_set()) {
+        char text[20];
+
+        for (const char* stamp = walltime_format(text, sizeof(text), walltime_now(), WALLTIME_TIME); *stamp != 0; stamp++) {
+            this->push(*stamp);
+        }
+
+        this->push(' ');
+    }
+
+    this->lineStart = value == '\n';
+
+    return this->push(value);
+}
+
+void LogQueue::process() {
+    while (this->count > 0) {
+        const int space = Serial.availableForWrite();
+
+        if (space <= 0) {
+            return;
+        }
+
+        // only the part up to the end of the ring can be sent in one go
+        unsigned char length = min(this->count, LOG_QUEUE_SIZE - this->head);
+        length = min((int)length, space);
+
+        Serial.write((const uint8_t*)this->buffer + this->head, length);
+
+        this->head = (this->head + length) % LOG_QUEUE_SIZE;
+        this->count -= length;
+    }
+}
@@ -0,0 +1,356 @@
+#include "Macros.hpp"
+#include "HidOutput.hpp"
+#include "WallTime.hpp"
+#include "Log.hpp"
+#include "EepromLayout.h"
+#include <EEPROM.h>
+
+Macros macros;
+
+bool Macros::append(const EMacroStep step, const unsigned char value) {
+    if (this->length + 2 > MACRO_SIZE) {
+        return false;
+    }
+
+    this->buffer[this->length++] = step;
+    this->buffer[this->length++] = value;
+
+    return true;
+}
+
+void Macros::startRecording() {
+    this->playing = false;
+    this->recording = true;
+    this->length = 0;
+
+    Log.println(F("Macro recording"));
+}
+
+void Macros::stopRecording() {
+    this->recording = false;
+
+    Log.print(F("Macro recorded, ")); Log.print(this->length, DEC); Log.println(F(" bytes"));
+}
+
+bool Macros::isRecording() const {
+    return this->recording;
+}
+
+void Macros::record(const EMacroStep step, const unsigned char value) {
+    if (!this->recording) {
+        return;
+    }
+
+    const unsigned long time = millis();
+
+    if (this->length > 0) {
+        const unsigned long delay = (time - this->lastRecorded) / MACRO_DELAY_UNIT;
+
+        if (delay > 0) {
+            this->append(MACRO_DELAY, min(delay, 255UL));
+        }
+    }
+
+    this->lastRecorded = time;
+    this->append(step, value);
+}
+
+int Macros::address(const unsigned char slot) const {
+    return ADDR_EEPROM_MACROS + slot * (MACRO_SIZE + 1);
+}
+
+void Macros::save(const unsigned char slot) {
+    const int address = this->address(slot);
+
+    EEPROM.update(address, this->length);
+
+    for (unsigned char i = 0; i < this->length; i++) {
+        EEPROM.update(address + 1 + i, this->buffer[i]);
+    }
+
+    Log.print(F("Macro saved to slot ")); Log.println(slot + 1, DEC);
+}
+
+bool Macros::append(const unsigned char slot, const EMacroStep step, const unsigned char value) {
+    const int address = this->address(slot);
+    const unsigned char length = this->used(slot);
+
+    if (length + 2 > MACRO_SIZE) {
+        return false;
+    }
+
+    EEPROM.update(address + 1 + length, step);
+    EEPROM.update(address + 2 + length, value);
+    EEPROM.update(address, length + 2);
+
+    return true;
+}
+
+bool Macros::appendText(const unsigned char slot, const char* text) {
+    const int address = this->address(slot);
+    const unsigned char length = this->used(slot);
+    const unsigned char size = strlen(text);
+
+    if (length + 2 + size > MACRO_SIZE) {
+        return false;
+    }
+
+    EEPROM.update(address + 1 + length, MACRO_TEXT);
+    EEPROM.update(address + 2 + length, size);
+
+    for (unsigned char i = 0; i < size; i++) {
+        EEPROM.update(address + 3 + length + i, text[i]);
+    }
+
+    EEPROM.update(address, length + 2 + size);
+
+    return true;
+}
+
+void Macros::erase(const unsigned char slot) {
+    EEPROM.update(this->address(slot), 0);
+}
+
+unsigned char Macros::used(const unsigned char slot) const {
+    const unsigned char length = EEPROM.read(this->address(slot));
+
+    // erased EEPROM reads 0xFF
+    return length > MACRO_SIZE ? 0 : length;
+}
+
+void Macros::print(Print* out) const {
+    for (unsigned char slot = 0; slot < MACRO_SLOTS; slot++) {
+        out->print(slot + 1, DEC);
+        out->print(F(": "));
+        out->print(this->used(slot), DEC);
+        out->print('/');
+        out->print(MACRO_SIZE, DEC);
+        out->println(F(" bytes"));
+    }
+}
+
+// text steps are split to fit the console line after "macros add N text "
+#define MACRO_LIST_TEXT 20
+
+void Macros::list(const unsigned char slot, Print* out) const {
+    const int address = this->address(slot) + 1;
+    const unsigned char length = this->used(slot);
+
+    out->print(F("macros delete "));
+    out->println(slot + 1, DEC);
+
+    for (unsigned char i = 0; i + 1 < length; i += 2) {
+        const unsigned char step = EEPROM.read(address + i);
+        const unsigned char value = EEPROM.read(address + i + 1);
+
+        out->print(F("macros add "));
+        out->print(slot + 1, DEC);
+
+        switch (step) {
+            case MACRO_PRESS:
+                out->print(F(" press "));
+                out->println(value, DEC);
+                break;
+            case MACRO_RELEASE:
+                out->print(F(" release "));
+                out->println(value, DEC);
+                break;
+            case MACRO_DELAY:
+                out->print(F(" delay "));
+                out->println(value * MACRO_DELAY_UNIT, DEC);
+                break;
+            case MACRO_WAIT:
+                out->print(F(" delay "));
+                out->println((unsigned long)value * MACRO_WAIT_UNIT, DEC);
+                break;
+            case MACRO_MODIFIERS:
+                out->print(F(" mods "));
+                out->println(value, DEC);
+                break;
+            case MACRO_REPEAT:
+                out->print(F(" repeat "));
+                out->println(value, DEC);
+                break;
+            case MACRO_TIMESTAMP:
+                out->print(F(" stamp "));
+                out->println(value, DEC);
+                break;
+            case MACRO_TEXT:
+                out->print(F(" text "));
+
+                for (unsigned char j = 0; j < value && i + 2 + j < length; j++) {
+                    if (j > 0 && j % MACRO_LIST_TEXT == 0) {
+                        out->println();
+                        out->print(F("macros add "));
+                        out->print(slot + 1, DEC);
+                        out->print(F(" text "));
+                    }
+
+                    out->write(EEPROM.read(address + i + 2 + j));
+                }
+
+                out->println();
+                i += value;
+                break;
+            default:
+                out->println(F(" ?"));
+                break;
+        }
+    }
+}
+
+void Macros::setEnabled(const bool enabled) {
+    this->enabled = enabled;
+}
+
+void Macros::play(const unsigned char slot) {
+    if (this->recording || !this->enabled) {
+        return;
+    }
+
+    const int address = this->address(slot);
+    this->length = this->used(slot);
+
+    for (unsigned char i = 0; i < this->length; i++) {
+        this->buffer[i] = EEPROM.read(address + 1 + i);
+    }
+
+    if (this->length == 0) {
+        return;
+    }
+
+    this->playing = true;
+    this->position = 0;
+    this->nextStep = millis();
+    this->modifiers = 0;
+    this->textLeft = 0;
+    this->repeating = false;
+    this->repeatStart = 0;
+}
+
+bool Macros::isPlaying() const {
+    return this->playing;
+}
+
+void Macros::stop() {
+    if (this->playing) {
+        this->playing = false;
+        hid.stopTyping();
+        hid.releaseAll();
+
+        Log.println(F("Macro stopped"));
+    }
+}
+
+void Macros::tick(const unsigned long ms) {
+    // typed text has to be out before the next key goes down, and one step
+    // per loop keeps the rest of the firmware (and the watchdog) going
+    // signed, so the deadline still works when millis() wraps around
+    if (!this->playing || (long)(ms - this->nextStep) < 0 || hid.isTyping()) {
+        return;
+    }
+
+    if (this->textLeft > 0 && this->position < this->length) {
+        hid.write(this->buffer[this->position++]);
+        this->textLeft--;
+        this->nextStep = ms + MACRO_REPORT_INTERVAL;
+        return;
+    }
+
+    if (this->position >= this->length) {
+        // a recording cut short by a full buffer can end with keys down
+        hid.releaseAll();
+        this->playing = false;
+        return;
+    }
+
+    const unsigned char step = this->buffer[this->position++];
+    const unsigned char value = this->buffer[this->position++];
+
+    this->runStep(step, value, ms);
+}
+
+void Macros::runStep(const unsigned char step, const unsigned char value, const unsigned long ms) {
+    switch (step) {
+        case MACRO_PRESS:
+            hid.press((KeyboardKeycode)value, true);
+            this->nextStep = ms + MACRO_REPORT_INTERVAL;
+            break;
+        case MACRO_RELEASE:
+            hid.release((KeyboardKeycode)value, true);
+            this->nextStep = ms + MACRO_REPORT_INTERVAL;
+            break;
+        case MACRO_DELAY:
+            this->nextStep = ms + value * MACRO_DELAY_UNIT;
+            break;
+        case MACRO_WAIT:
+            this->nextStep = ms + value * (unsigned long)MACRO_WAIT_UNIT;
+            break;
+        case MACRO_MODIFIERS:
+            this->setModifiers(value);
+            this->nextStep = ms + MACRO_REPORT_INTERVAL;
+            break;
+        case MACRO_TEXT:
+            this->textLeft = value;
+            this->nextStep = ms;
+            break;
+        case MACRO_TIMESTAMP:
+            if (walltime_is_set()) {
+                char text[20];
+                // queued, the next step waits for it to be typed
+                hid.print(walltime_format(text, sizeof(text), walltime_now(), (EWallTimeFormat)value));
+            }
+
+            this->nextStep = ms;
+            break;
+        case MACRO_REPEAT:
+            if (!this->repeating) {
+                this->repeating = true;
+                this->repeatsLeft = value > 0 ? value - 1 : 0;
+            }
+
+            if (this->repeatsLeft > 0) {
+                this->repeatsLeft--;
+                this->position = this->repeatStart;
+            } else {
+                this->repeating = false;
+                this->repeatStart = this->position;
+            }
+
+            this->nextStep = ms;
+            break;
+    }
+}
+
+void Macros::setModifiers(const unsigned char bits) {
+    for (unsigned char i = 0; i < 8; i++) {
+        const unsigned char bit = 1 << i;
+        const KeyboardKeycode key = (KeyboardKeycode)(KEY_LEFT_CTRL + i);
+
+        if ((bits & bit) && !(this->modifiers & bit)) {
+            hid.press(key, true);
+        } else if (!(bits & bit) && (this->modifiers & bit)) {
+            hid.release(key, true);
+        }
+    }
+
+    this->modifiers = bits;
+}
+
+bool Macros::draw(U8G2* u8g2) const {
+    if (!this->recording) {
+        return false;
+    }
+
+    u8g2->setFont(u8g2_font_ncenB08_tr);
+
+    const unsigned char width = u8g2->getStrWidth("REC") + 4;
+
+    u8g2->setDrawColor(1);
+    u8g2->drawBox(128 - width, 0, width, 11);
+    u8g2->setDrawColor(0);
+    u8g2->drawStr(128 - width + 2, 9, "REC");
+    u8g2->setDrawColor(1);
+
+    return true;
+}
@@ -0,0 +1,88 @@
+#include "MediaKeys.hpp"
+#include "HidOutput.hpp"
+
+typedef struct MediaName {
+    ConsumerKeycode key;
+    const char* name;
+} MediaName;
+
+const MediaName mediaNames[] = {
+    {MEDIA_PLAY_PAUSE, "Play"},
+    {MEDIA_STOP, "Stop"},
+    {MEDIA_PREVIOUS, "Prev"},
+    {MEDIA_NEXT, "Next"},
+    {MEDIA_VOLUME_UP, "Vol +"},
+    {MEDIA_VOLUME_DOWN, "Vol -"},
+    {MEDIA_VOLUME_MUTE, "Mute"},
+};
+
+static const char* media_name(const ConsumerKeycode key) {
+    for (const MediaName& entry : mediaNames) {
+        if (entry.key == key) {
+            return entry.name;
+        }
+    }
+
+    return nullptr;
+}
+
+ConsumerKeycode MediaKeys::getKey(unsigned char row, unsigned char column) const {
+    return this->mapping[row][column];
+}
+
+void MediaKeys::onPress(char row, char column) {
+    const ConsumerKeycode key = this->getKey(row, column);
+
+    if (key == HID_CONSUMER_UNASSIGNED) {
+        return;
+    }
+
+    hid.consumerPress(key);
+
+    this->last = key;
+    this->drawNext = true;
+}
+
+void MediaKeys::onRelease(char row, char column) {
+    const ConsumerKeycode key = this->getKey(row, column);
+
+    if (key != HID_CONSUMER_UNASSIGNED) {
+        hid.consumerRelease(key);
+    }
+}
+
+void MediaKeys::draw(U8G2* u8g2) {
+    if (!this->drawNext) return;
+    this->drawNext = false;
+
+    u8g2->clearBuffer();
+    u8g2->setFont(u8g2_font_5x8_tr);
+    u8g2->drawStr(0, 8, "Media");
+
+    const char* name = media_name(this->last);
+
+    if (name != nullptr) {
+        u8g2->setFont(u8g2_font_ncenB14_tr);
+        u8g2->drawStr(64 - u8g2->getStrWidth(name) / 2, 28, name);
+    }
+
+    u8g2->sendBuffer();
+}
+
+void MediaKeys::onShow() {
+    this->drawNext = true;
+    this->last = HID_CONSUMER_UNASSIGNED;
+}
+
+bool MediaKeys::describeKey(char row, char column, char* label) const {
+    const char* name = media_name(this->getKey(row, column));
+
+    if (name == nullptr) {
+        return false;
+    }
+
+    strncpy(label, name, HELP_LABEL_SIZE - 1);
+    label[HELP_LABEL_SIZE - 1] = 0;
+
+    return true;
+}
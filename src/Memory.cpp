@@ -0,0 +1,54 @@
+#include "Memory.hpp"
+#include <avr/interrupt.h>
+#include <avr/io.h>
+
+#define MEMORY_CANARY 0xAA
+
+extern char __data_start;
+extern char __bss_end;
+extern char __heap_start;
+extern char* __brkval;
+
+static char* heap_end() {
+    return __brkval == nullptr ? &__heap_start : __brkval;
+}
+
+void memory_paint() {
+    char marker;
+
+    cli();
+    // stay clear of this function's own frame
+    for (char* p = heap_end(); p < &marker - 32; p++) {
+        *p = MEMORY_CANARY;
+    }
+    sei();
+}
+
+unsigned short memory_static() {
+    return &__bss_end - &__data_start;
+}
+
+unsigned short memory_free() {
+    char marker;
+
+    return &marker - heap_end();
+}
+
+unsigned short memory_stack_headroom() {
+    const char* p = heap_end();
+    unsigned short untouched = 0;
+
+    while (*p == (char)MEMORY_CANARY && p < (const char*)SP) {
+        p++;
+        untouched++;
+    }
+
+    return untouched;
+}
+
+void memory_print(Print* out) {
+    out->print(F("ram: static=")); out->print(memory_static(), DEC);
+    out->print(F(" free=")); out->print(memory_free(), DEC);
+    out->print(F(" headroom=")); out->print(memory_stack_headroom(), DEC);
+    out->print(F(" of ")); out->println(RAMEND - RAMSTART + 1, DEC);
+}
@@ -0,0 +1,163 @@
+#include "MouseKeys.hpp"
+#include "HidOutput.hpp"
+#include "Format.hpp"
+
+const char mouseArrows[][7] PROGMEM = {"Down L", "Down", "Down R", "Left", "", "Right", "Up L", "Up", "Up R"};
+
+unsigned char MouseKeys::getChar(unsigned char row, unsigned char column) const {
+    return this->mapping[row][column];
+}
+
+// adds or takes away the direction of a digit, laid out like the keypad
+void MouseKeys::steer(const unsigned char input, const signed char sign) {
+    const unsigned char digit = input - '1';
+
+    this->dx += sign * (digit % 3 - 1);
+    this->dy -= sign * (digit / 3 - 1);
+}
+
+unsigned char MouseKeys::speed(const unsigned long ms) const {
+    return min((unsigned long)MOUSE_MAX_SPEED, MOUSE_MIN_SPEED + (ms - this->movingSince) / MOUSE_ACCEL_MS);
+}
+
+void MouseKeys::onPress(char row, char column) {
+    const unsigned char input = this->getChar(row, column);
+    const unsigned long ms = millis();
+
+    this->drawNext = true;
+
+    switch (input) {
+        case 'L':
+        case 'R': {
+            const unsigned char button = input == 'L' ? MOUSE_LEFT : MOUSE_RIGHT;
+
+            this->buttons |= button;
+            hid.mousePress(button);
+            break;
+        }
+        case 'u':
+        case 'd':
+            this->wheel += input == 'u' ? 1 : -1;
+            hid.mouseMove(0, 0, input == 'u' ? 1 : -1);
+            this->lastScroll = ms;
+            break;
+        default:
+            if (input >= '1' && input <= '9') {
+                if (this->moving++ == 0) {
+                    this->movingSince = ms;
+                }
+
+                this->steer(input, 1);
+            }
+            break;
+    }
+}
+
+void MouseKeys::onRelease(char row, char column) {
+    const unsigned char input = this->getChar(row, column);
+
+    this->drawNext = true;
+
+    switch (input) {
+        case 'L':
+        case 'R': {
+            const unsigned char button = input == 'L' ? MOUSE_LEFT : MOUSE_RIGHT;
+
+            hid.mouseRelease(button);
+            this->buttons &= ~button;
+            break;
+        }
+        case 'u':
+        case 'd':
+            this->wheel -= input == 'u' ? 1 : -1;
+            break;
+        default:
+            if (input >= '1' && input <= '9' && this->moving > 0) {
+                this->moving--;
+                this->steer(input, -1);
+            }
+            break;
+    }
+}
+
+void MouseKeys::draw(U8G2* u8g2) {
+    if (!this->drawNext) return;
+    this->drawNext = false;
+
+    char value[8];
+
+    u8g2->clearBuffer();
+    u8g2->setFont(u8g2_font_ncenB08_tr);
+    u8g2->drawStr(0, 10, "Mouse");
+
+    if (this->dx != 0 || this->dy != 0) {
+        const signed char x = constrain(this->dx, -1, 1);
+        const signed char y = constrain(this->dy, -1, 1);
+
+        char arrow[sizeof(mouseArrows[0])];
+
+        strcpy_P(arrow, mouseArrows[(1 - y) * 3 + x + 1]);
+        u8g2->drawStr(0, 28, arrow);
+        u8g2->drawStr(60, 28, format_unit(value, sizeof(value), this->speed(millis()), "px"));
+    }
+
+    // the buttons held down
+    u8g2->drawFrame(100, 14, 12, 16);
+    u8g2->drawFrame(111, 14, 12, 16);
+
+    if (this->buttons & MOUSE_LEFT) u8g2->drawBox(100, 14, 12, 16);
+    if (this->buttons & MOUSE_RIGHT) u8g2->drawBox(111, 14, 12, 16);
+
+    u8g2->sendBuffer();
+}
+
+void MouseKeys::tick(const unsigned long ms) {
+    if ((this->dx != 0 || this->dy != 0) && ms - this->lastMove >= MOUSE_TICK_MS) {
+        const signed char step = this->speed(ms);
+
+        this->lastMove = ms;
+        hid.mouseMove(constrain(this->dx, -1, 1) * step, constrain(this->dy, -1, 1) * step);
+
+        if (step != this->shownSpeed) {
+            this->shownSpeed = step;
+            this->drawNext = true;
+        }
+    }
+
+    if (this->wheel != 0 && ms - this->lastScroll >= MOUSE_SCROLL_MS) {
+        this->lastScroll = ms;
+        hid.mouseMove(0, 0, constrain(this->wheel, -1, 1));
+    }
+}
+
+void MouseKeys::onShow() {
+    this->drawNext = true;
+}
+
+void MouseKeys::onHide() {
+    hid.mouseRelease(this->buttons);
+
+    this->dx = 0;
+    this->dy = 0;
+    this->moving = 0;
+    this->wheel = 0;
+    this->buttons = 0;
+}
+
+bool MouseKeys::describeKey(char row, char column, char* label) const {
+    const unsigned char input = this->getChar(row, column);
+
+    switch (input) {
+        case 0: return false;
+        case 'L': strcpy_P(label, PSTR("Click")); break;
+        case 'R': strcpy_P(label, PSTR("Right")); break;
+        case 'u': strcpy_P(label, PSTR("Scrl up")); break;
+        case 'd': strcpy_P(label, PSTR("Scrl dn")); break;
+        default:
+            strncpy_P(label, mouseArrows[input - '1'], HELP_LABEL_SIZE - 1);
+            label[HELP_LABEL_SIZE - 1] = 0;
+            break;
+    }
+
+    return true;
+}
@@ -0,0 +1,80 @@
+#include "Notifier.hpp"
+#include "HID-Project.h"
+
+Notifier notifier;
+
+typedef struct NotifyLed {
+    unsigned char led;
+    unsigned char event;
+    const char* on;
+    const char* off;
+} NotifyLed;
+
+static const char capsOn[] PROGMEM = "Caps on";
+static const char capsOff[] PROGMEM = "Caps off";
+static const char numOn[] PROGMEM = "Num on";
+static const char numOff[] PROGMEM = "Num off";
+static const char scrollOn[] PROGMEM = "Scroll on";
+static const char scrollOff[] PROGMEM = "Scroll off";
+
+static const NotifyLed notifyLeds[] PROGMEM = {
+    {LED_CAPS_LOCK, NOTIFY_CAPS_LOCK, capsOn, capsOff},
+    {LED_NUM_LOCK, NOTIFY_NUM_LOCK, numOn, numOff},
+    {LED_SCROLL_LOCK, NOTIFY_SCROLL_LOCK, scrollOn, scrollOff},
+};
+
+void Notifier::show(const char* text) {
+    this->text = text;
+    this->shownAt = millis();
+}
+
+void Notifier::post(const __FlashStringHelper* text) {
+    if (NOTIFY_EVENTS & NOTIFY_FIRMWARE) {
+        this->show((const char*)text);
+    }
+}
+
+void Notifier::tick(const unsigned long ms) {
+    const unsigned char leds = BootKeyboard.getLeds();
+    const unsigned char changed = leds ^ this->leds;
+
+    this->leds = leds;
+
+    for (const NotifyLed& stored : notifyLeds) {
+        NotifyLed entry;
+
+        memcpy_P(&entry, &stored, sizeof(entry));
+
+        if ((changed & entry.led) && (NOTIFY_EVENTS & entry.event)) {
+            this->show(leds & entry.led ? entry.on : entry.off);
+        }
+    }
+}
+
+bool Notifier::draw(U8G2* u8g2, const unsigned long time) {
+    if (this->text == nullptr) {
+        return false;
+    }
+
+    if (time - this->shownAt >= NOTIFY_MS) {
+        this->text = nullptr;
+        return false;
+    }
+
+    char text[NOTIFY_LABEL_SIZE + 1];
+
+    strncpy_P(text, this->text, NOTIFY_LABEL_SIZE);
+    text[NOTIFY_LABEL_SIZE] = 0;
+
+    u8g2->setFont(u8g2_font_ncenB08_tr);
+
+    const unsigned char width = u8g2->getStrWidth(text) + 4;
+
+    u8g2->setDrawColor(1);
+    u8g2->drawBox(0, 0, width, 11);
+    u8g2->setDrawColor(0);
+    u8g2->drawStr(2, 9, text);
+    u8g2->setDrawColor(1);
+
+    return true;
+}
@@ -1,21 +1,49 @@
 #include "Numpad.hpp"
 #include "NumpadGraphics.h"
+#include "HidOutput.hpp"
 
 KeyboardKeycode Numpad::getChar(unsigned char row, unsigned char column) const {
     return this->mapping[row][column];
 }
 
+unsigned char Numpad::getEcho(unsigned char row, unsigned char column) const {
+    return this->echoMapping[row][column];
+}
+
 void Numpad::onPress(char row, char column) {
     const KeyboardKeycode _char = this->getChar(row, column);
 
-    BootKeyboard.press(_char);
+    hid.press(_char);
+    this->addEcho(this->getEcho(row, column));
+}
+
+void Numpad::addEcho(const unsigned char character) {
+    unsigned char length = strlen(this->echo);
+
+    if (character == 0) {
+        return;
+    } else if (character == '\b') {
+        if (length > 0) {
+            this->echo[length - 1] = 0;
+        }
+
+        return;
+    }
+
+    if (length == NUMPAD_ECHO_SIZE) {
+        // scroll out the oldest character
+        memmove(this->echo, this->echo + 1, --length);
+    }
+
+    this->echo[length++] = character;
+    this->echo[length] = 0;
 }
 
 void Numpad::onRelease(char row, char column) {
     const KeyboardKeycode _char = this->getChar(row, column);
     this->drawNext = true;
 
-    BootKeyboard.release(_char);
+    hid.release(_char);
 }
 
 void Numpad::draw(U8G2* u8g2) {
@@ -27,8 +55,15 @@ void Numpad::draw(U8G2* u8g2) {
 
     u8g2->clearBuffer();
     u8g2->drawXBMP(4, 1, sprite->width, sprite->height, sprite->data);    
-    u8g2->setFont(u8g2_font_sticker_mel_tr);
-    u8g2->drawStr(38,24,"Love You!!");
+
+    if (this->echo[0] == 0) {
+        u8g2->setFont(u8g2_font_sticker_mel_tr);
+        u8g2->drawStr(38,24,"Love You!!");
+    } else {
+        u8g2->setFont(u8g2_font_5x8_tr);
+        u8g2->drawStr(38, 20, this->echo);
+    }
+
     u8g2->sendBuffer();
 }
 
@@ -39,6 +74,41 @@ void Numpad::tick(const unsigned long ms) {
     this->ledState = leds;
 }
 
+bool Numpad::describeKey(char row, char column, char* label) const {
+    const KeyboardKeycode key = this->getChar(row, column);
+
+    if (key >= KEYPAD_1 && key <= KEYPAD_0) {
+        label[0] = key == KEYPAD_0 ? '0' : '1' + (key - KEYPAD_1);
+        label[1] = 0;
+        return true;
+    }
+
+    if (key >= KEY_F13 && key <= KEY_F16) {
+        label[0] = 'F';
+        label[1] = '1';
+        label[2] = '3' + (key - KEY_F13);
+        label[3] = 0;
+        return true;
+    }
+
+    switch (key) {
+        case KEY_SCROLL_LOCK: strcpy_P(label, PSTR("ScrLk")); break;
+        case KEY_F14: strcpy_P(label, PSTR("F14")); break;
+        case KEY_DELETE: strcpy_P(label, PSTR("Del")); break;
+        case KEY_BACKSPACE: strcpy_P(label, PSTR("Bksp")); break;
+        case KEY_NUM_LOCK: strcpy_P(label, PSTR("NumLk")); break;
+        case KEYPAD_DIVIDE: strcpy_P(label, PSTR("/")); break;
+        case KEYPAD_MULTIPLY: strcpy_P(label, PSTR("*")); break;
+        case KEYPAD_SUBTRACT: strcpy_P(label, PSTR("-")); break;
+        case KEYPAD_ADD: strcpy_P(label, PSTR("+")); break;
+        case KEYPAD_ENTER: strcpy_P(label, PSTR("Enter")); break;
+        case KEYPAD_DOT: strcpy_P(label, PSTR(".")); break;
+        default: return false;
+    }
+
+    return true;
+}
+
 void Numpad::onShow() {
     this->drawNext = true;
 }
\ No newline at end of file
@@ -0,0 +1,190 @@
+#include "PinLock.hpp"
+#include "EepromLayout.h"
+#include "HidOutput.hpp"
+#include "Log.hpp"
+#include "Format.hpp"
+#include <EEPROM.h>
+
+// erased EEPROM, no PIN stored
+#define PIN_NONE 0xFFFFFFFFUL
+
+// doublings of PIN_LOCKOUT_MS past which PIN_MAX_LOCKOUT_MS applies anyway
+#define PIN_MAX_LOCKOUTS 7
+
+PinLock pinLock;
+
+// 32 bit FNV-1a
+unsigned long pin_hash(const char* pin) {
+    unsigned long hash = 2166136261UL;
+
+    for (; *pin != 0; pin++) {
+        hash ^= (unsigned char)*pin;
+        hash *= 16777619UL;
+    }
+
+    return hash;
+}
+
+unsigned long pin_load() {
+    unsigned long hash;
+    EEPROM.get(ADDR_EEPROM_PIN, hash);
+
+    return hash;
+}
+
+unsigned char PinLock::getChar(unsigned char row, unsigned char column) const {
+    return this->mapping[row][column];
+}
+
+unsigned long PinLock::lockoutLeft(const unsigned long ms) const {
+    const unsigned long waited = ms - this->lockoutStart;
+
+    return waited < this->lockoutMs ? this->lockoutMs - waited : 0;
+}
+
+void PinLock::onPress(char row, char column) {
+    const unsigned char input = this->getChar(row, column);
+    const unsigned char length = strlen(this->entry);
+
+    this->drawNext = true;
+
+    if (this->lockoutLeft(millis()) > 0) {
+        return;
+    }
+
+    if (input >= '0' && input <= '9' && length < PIN_MAX_LENGTH) {
+        this->entry[length] = input;
+        this->entry[length + 1] = 0;
+        this->wrong = false;
+    } else if (input == '\b' && length > 0) {
+        this->entry[length - 1] = 0;
+    } else if (input == 'C') {
+        this->entry[0] = 0;
+    } else if (input == '\n') {
+        this->submit();
+    }
+}
+
+void PinLock::submit() {
+    if (pin_hash(this->entry) == pin_load()) {
+        Log.println(F("Unlocked"));
+
+        this->locked = false;
+        this->failures = 0;
+        this->lockouts = 0;
+        this->lockoutMs = 0;
+        this->saveFailures();
+        hid.unblock(HID_BLOCK_LOCK);
+    } else {
+        Log.println(F("Wrong PIN"));
+
+        this->wrong = true;
+
+        if (this->failures < 0xFE) {
+            this->failures++;
+        }
+
+        if (this->failures >= PIN_ATTEMPTS) {
+            // guessing gets slower and slower
+            if (this->lockouts < PIN_MAX_LOCKOUTS) {
+                this->lockouts++;
+            }
+
+            this->startLockout();
+        }
+
+        this->saveFailures();
+    }
+
+    this->entry[0] = 0;
+}
+
+void PinLock::saveFailures() const {
+    EEPROM.update(ADDR_EEPROM_PIN_FAILURES, this->failures);
+    EEPROM.update(ADDR_EEPROM_PIN_FAILURES + 1, this->lockouts);
+}
+
+void PinLock::startLockout() {
+    this->lockoutMs = min(PIN_LOCKOUT_MS << (this->lockouts - 1), PIN_MAX_LOCKOUT_MS);
+    this->lockoutStart = millis();
+}
+
+void PinLock::draw(U8G2* u8g2) {
+    if (!this->drawNext) return;
+    this->drawNext = false;
+
+    char stars[PIN_MAX_LENGTH + 1];
+    const unsigned char length = strlen(this->entry);
+
+    memset(stars, '*', length);
+    stars[length] = 0;
+
+    u8g2->clearBuffer();
+    u8g2->setFont(u8g2_font_ncenB08_tr);
+    u8g2->drawStr(0, 10, this->wrong ? "Wrong PIN" : "Enter PIN");
+
+    const unsigned long left = this->lockoutLeft(millis());
+
+    if (left > 0) {
+        char wait[8];
+
+        u8g2->drawStr(0, 28, "Try again in");
+        u8g2->drawStr(80, 28, format_clock(wait, sizeof(wait), (left + 999) / 1000));
+    } else {
+        u8g2->drawStr(0, 28, stars);
+    }
+
+    u8g2->sendBuffer();
+}
+
+void PinLock::tick(const unsigned long ms) {
+    const unsigned long second = (this->lockoutLeft(ms) + 999) / 1000;
+
+    if (second != this->shownSecond) {
+        this->shownSecond = second;
+        this->drawNext = true;
+    }
+}
+
+void PinLock::onShow() {
+    this->drawNext = true;
+    this->entry[0] = 0;
+}
+
+void PinLock::lock() {
+    this->locked = true;
+    this->wrong = false;
+
+    // erased EEPROM reads as all ones
+    const unsigned char failures = EEPROM.read(ADDR_EEPROM_PIN_FAILURES);
+    const unsigned char lockouts = EEPROM.read(ADDR_EEPROM_PIN_FAILURES + 1);
+
+    this->failures = failures == 0xFF ? 0 : failures;
+    this->lockouts = lockouts > PIN_MAX_LOCKOUTS ? 0 : lockouts;
+
+    // a lockout cut short by unplugging starts over
+    if (this->failures >= PIN_ATTEMPTS && this->lockouts > 0) {
+        this->startLockout();
+    }
+
+    hid.block(HID_BLOCK_LOCK);
+}
+
+bool PinLock::isLocked() const {
+    return this->locked;
+}
+
+bool PinLock::isSet() const {
+    const unsigned long hash = pin_load();
+
+    // zeros are left behind by other firmware rather than a PIN
+    return hash != PIN_NONE && hash != 0;
+}
+
+void PinLock::setPin(const char* pin) const {
+    EEPROM.put(ADDR_EEPROM_PIN, pin_hash(pin));
+}
+
+void PinLock::clearPin() const {
+    EEPROM.put(ADDR_EEPROM_PIN, PIN_NONE);
+}
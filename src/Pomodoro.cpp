@@ -0,0 +1,128 @@
+#include "Pomodoro.hpp"
+#include "Format.hpp"
+#include "Arduino.h"
+
+unsigned char Pomodoro::getChar(unsigned char row, unsigned char column) const {
+    return this->mapping[row][column];
+}
+
+unsigned long Pomodoro::length() const {
+    return (this->working ? POMODORO_WORK_MINUTES : POMODORO_BREAK_MINUTES) * 60000UL;
+}
+
+unsigned long Pomodoro::progress(const unsigned long ms) const {
+    return this->running ? ms - this->started : this->elapsed;
+}
+
+// ends the current interval, the next one picks up where it left off
+void Pomodoro::next() {
+    if (this->working) {
+        this->rounds++;
+    }
+
+    this->started += this->length();
+    this->elapsed = 0;
+    this->working = !this->working;
+}
+
+void Pomodoro::onPress(char row, char column) {
+    const unsigned long ms = millis();
+
+    this->drawNext = true;
+
+    if (this->flashing) {
+        // the key only stops the flashing
+        this->flashing = false;
+        return;
+    }
+
+    switch (this->getChar(row, column)) {
+        case '\n':
+            if (this->running) {
+                this->elapsed = ms - this->started;
+            } else {
+                this->started = ms - this->elapsed;
+            }
+
+            this->running = !this->running;
+            break;
+        case 's':
+            this->started = ms - this->length();
+            this->next();
+            break;
+        case 'C':
+            this->working = true;
+            this->running = false;
+            this->rounds = 0;
+            this->elapsed = 0;
+            break;
+    }
+}
+
+void Pomodoro::draw(U8G2* u8g2) {
+    if (!this->drawNext) return;
+    this->drawNext = false;
+
+    const unsigned long ms = millis();
+
+    u8g2->clearBuffer();
+
+    if (this->flashing && (ms / POMODORO_FLASH_MS) % 2 == 0) {
+        u8g2->drawBox(0, 0, 128, 32);
+        u8g2->setDrawColor(0);
+    }
+
+    char value[8];
+    const unsigned long done = min(this->progress(ms), this->length());
+
+    u8g2->setFont(u8g2_font_5x8_tr);
+    u8g2->drawStr(0, 8, this->working ? "Work" : "Break");
+    u8g2->drawStr(30, 8, format_int(value, sizeof(value), this->rounds));
+
+    if (!this->running) {
+        u8g2->drawStr(98, 8, "Paused");
+    }
+
+    u8g2->setFont(u8g2_font_ncenB14_tr);
+    u8g2->drawStr(0, 25, format_clock(value, sizeof(value), (this->length() - done + 999) / 1000));
+
+    u8g2->drawFrame(0, 28, 128, 4);
+    u8g2->drawBox(0, 28, done * 128 / this->length(), 4);
+
+    u8g2->setDrawColor(1);
+    u8g2->sendBuffer();
+}
+
+void Pomodoro::tick(const unsigned long ms) {
+    if (this->running && ms - this->started >= this->length()) {
+        // more than one if it ran out while another mode was shown
+        while (ms - this->started >= this->length()) {
+            this->next();
+        }
+
+        this->flashing = true;
+    }
+
+    // a second passed or the flash needs to toggle
+    const unsigned long second = this->flashing ? ms / POMODORO_FLASH_MS : this->progress(ms) / 1000;
+
+    if (second != this->shownSecond) {
+        this->shownSecond = second;
+        this->drawNext = true;
+    }
+}
+
+void Pomodoro::onShow() {
+    this->drawNext = true;
+}
+
+bool Pomodoro::describeKey(char row, char column, char* label) const {
+    switch (this->getChar(row, column)) {
+        case 'C': strcpy_P(label, PSTR("Reset")); break;
+        case 's': strcpy_P(label, PSTR("Skip")); break;
+        case '\n': strcpy_P(label, PSTR("Start")); break;
+        default: return false;
+    }
+
+    return true;
+}
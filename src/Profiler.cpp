@@ -0,0 +1,48 @@
+#include "Profiler.hpp"
+
+#if FEATURE_PROFILING
+Profiler profiler;
+#endif
+
+const char profileLoop[] PROGMEM = "loop";
+const char profileScan[] PROGMEM = "scan";
+const char profileTick[] PROGMEM = "tick";
+const char profileDraw[] PROGMEM = "draw";
+const char* const profileNames[PROFILE_SECTIONS] PROGMEM = {profileLoop, profileScan, profileTick, profileDraw};
+
+void Profiler::begin(const EProfileSection section) {
+    this->started[section] = micros();
+}
+
+void Profiler::end(const EProfileSection section) {
+    const unsigned long elapsed = micros() - this->started[section];
+
+    this->total[section] += elapsed;
+    this->count[section]++;
+
+    if (elapsed > this->peak[section]) {
+        this->peak[section] = elapsed;
+    }
+}
+
+void Profiler::print(Print* out) {
+    for (unsigned char i = 0; i < PROFILE_SECTIONS; i++) {
+        out->print((const __FlashStringHelper*)pgm_read_ptr(&profileNames[i]));
+        out->print(F(": avg="));
+        out->print(this->count[i] > 0 ? this->total[i] / this->count[i] : 0, DEC);
+        out->print(F("us max="));
+        out->print(this->peak[i], DEC);
+        out->print(F("us n="));
+        out->println(this->count[i], DEC);
+    }
+
+    this->reset();
+}
+
+void Profiler::reset() {
+    for (unsigned char i = 0; i < PROFILE_SECTIONS; i++) {
+        this->total[i] = 0;
+        this->peak[i] = 0;
+        this->count[i] = 0;
+    }
+}
@@ -0,0 +1,260 @@
+#include "ProgrammerCalculator.hpp"
+#include "Arduino.h"
+
+unsigned char ProgrammerCalculator::getChar(unsigned char row, unsigned char column) const {
+    return this->mapping[row][column];
+}
+
+bool ProgrammerCalculator::hasLongPress(const char input) const {
+    switch (input) {
+        case '+':
+        case '-':
+        case 'x':
+        case '/':
+        case '<':
+            return true;
+        default:
+            return this->base == 16 && input >= '1' && input <= '6';
+    }
+}
+
+void ProgrammerCalculator::onPress(char row, char column) {
+    const char input = this->getChar(row, column);
+
+    this->heldKeys.press(KEY_ID(row, column), input, this->hasLongPress(input));
+    this->deliver();
+}
+
+void ProgrammerCalculator::onRelease(char row, char column) {
+    this->heldKeys.release(KEY_ID(row, column));
+    this->deliver();
+}
+
+void ProgrammerCalculator::deliver() {
+    for (char input = this->heldKeys.next(); input != 0; input = this->heldKeys.next()) {
+        this->doInput(input);
+    }
+}
+
+void ProgrammerCalculator::onLongPress(char row, char column) {
+    const char input = this->getChar(row, column);
+
+    if (!this->heldKeys.longPress(KEY_ID(row, column))) {
+        return;
+    }
+
+    switch (input) {
+        case '+': this->doInput('|'); break;
+        case 'x': this->doInput('&'); break;
+        case '-': this->doInput('^'); break;
+        case '/': this->doInput('~'); break;
+        case '<': this->doInput('>'); break;
+        default:
+            // 1 to 6 are A to F
+            this->doDigit(10 + input - '1');
+            this->drawNext = true;
+            break;
+    }
+}
+
+void ProgrammerCalculator::doInput(const char input) {
+    this->drawNext = true;
+
+    if (input >= '0' && input <= '9') {
+        this->doDigit(input - '0');
+        return;
+    }
+
+    switch (input) {
+        case 'B': this->base = 2; break;
+        case 'O': this->base = 8; break;
+        case 'D': this->base = 10; break;
+        case 'H': this->base = 16; break;
+        case 'C':
+            if (this->staleInput) {
+                this->result = 0;
+                this->pendingOperation = 0;
+            }
+
+            this->input = 0;
+            this->staleInput = true;
+            this->error = false;
+            break;
+        case '~':
+            // works on what's shown right away
+            if (this->staleInput) {
+                this->result = ~this->result;
+            } else {
+                this->input = ~this->input;
+            }
+            break;
+        case '\n':
+            this->doMath(this->pendingOperation);
+            this->pendingOperation = 0;
+            this->staleInput = true;
+            break;
+        default:
+            if (!this->staleInput) {
+                this->doMath(this->pendingOperation);
+            }
+
+            this->pendingOperation = input;
+            this->staleInput = true;
+            break;
+    }
+}
+
+void ProgrammerCalculator::doDigit(const unsigned char digit) {
+    if (digit >= this->base) {
+        return;
+    }
+
+    if (this->staleInput) {
+        this->input = 0;
+        this->staleInput = false;
+    }
+
+    const uint64_t value = this->input;
+
+    // digits that would push bits out the top are ignored
+    if (value > (UINT64_MAX - digit) / this->base) {
+        return;
+    }
+
+    this->input = value * this->base + digit;
+}
+
+void ProgrammerCalculator::doMath(const char op) {
+    const int64_t value = this->input;
+
+    this->error = false;
+
+    switch (op) {
+        case '+': this->result += value; break;
+        case '-': this->result -= value; break;
+        case 'x': this->result *= value; break;
+        case '/':
+            if (value == 0) {
+                this->error = true;
+                return;
+            }
+
+            this->result /= value;
+            break;
+        case '|': this->result |= value; break;
+        case '&': this->result &= value; break;
+        case '^': this->result ^= value; break;
+        case '<': this->result = value >= 64 ? 0 : (uint64_t)this->result << value; break;
+        case '>': this->result = value >= 64 ? 0 : (uint64_t)this->result >> value; break;
+        default: this->result = value; break;
+    }
+}
+
+char* ProgrammerCalculator::format(char* buffer, const int64_t value) const {
+    const bool negative = this->base == 10 && value < 0;
+    uint64_t remaining = negative ? -(uint64_t)value : (uint64_t)value;
+    char digits[PROGRAMMER_VALUE_SIZE];
+    unsigned char length = 0;
+
+    do {
+        const unsigned char digit = remaining % this->base;
+        digits[length++] = digit < 10 ? '0' + digit : 'A' + digit - 10;
+        remaining /= this->base;
+    } while (remaining > 0);
+
+    unsigned char i = 0;
+
+    if (negative) {
+        buffer[i++] = '-';
+    }
+
+    while (length > 0) {
+        buffer[i++] = digits[--length];
+    }
+
+    buffer[i] = 0;
+
+    return buffer;
+}
+
+void ProgrammerCalculator::draw(U8G2* u8g2) {
+    if (!this->drawNext) return;
+    this->drawNext = false;
+
+    char value[PROGRAMMER_VALUE_SIZE + 1];
+
+    u8g2->clearBuffer();
+    u8g2->setFont(u8g2_font_5x8_tr);
+
+    switch (this->base) {
+        case 2: u8g2->drawStr(0, 8, "BIN"); break;
+        case 8: u8g2->drawStr(0, 8, "OCT"); break;
+        case 10: u8g2->drawStr(0, 8, "DEC"); break;
+        case 16: u8g2->drawStr(0, 8, "HEX"); break;
+    }
+
+    if (this->error) {
+        u8g2->drawStr(20, 8, "E");
+    }
+
+    if (this->pendingOperation != 0) {
+        const unsigned char length = strlen(this->format(value, this->result));
+
+        value[length] = ' ';
+        value[length + 1] = this->pendingOperation == 'x' ? '*' : this->pendingOperation;
+        value[length + 2] = 0;
+
+        // wide binary values keep their low end
+        const char* shown = value;
+
+        while (u8g2->getStrWidth(shown) > 96) shown++;
+
+        u8g2->drawStr(128 - u8g2->getStrWidth(shown), 8, shown);
+    }
+
+    this->format(value, this->staleInput ? this->result : this->input);
+
+    u8g2->setFont(u8g2_font_ncenB14_tr);
+
+    if (u8g2->getStrWidth(value) > 128) {
+        u8g2->setFont(u8g2_font_5x8_tr);
+    }
+
+    const char* shown = value;
+
+    while (u8g2->getStrWidth(shown) > 128) shown++;
+
+    u8g2->drawStr(128 - u8g2->getStrWidth(shown), 31, shown);
+
+    u8g2->sendBuffer();
+}
+
+void ProgrammerCalculator::onShow() {
+    this->drawNext = true;
+    this->heldKeys.clear();
+}
+
+bool ProgrammerCalculator::describeKey(char row, char column, char* label) const {
+    const unsigned char input = this->getChar(row, column);
+
+    switch (input) {
+        case 'B': strcpy_P(label, PSTR("BIN")); break;
+        case 'O': strcpy_P(label, PSTR("OCT")); break;
+        case 'D': strcpy_P(label, PSTR("DEC")); break;
+        case 'H': strcpy_P(label, PSTR("HEX")); break;
+        case 'C': strcpy_P(label, PSTR("Clear")); break;
+        case '+': strcpy_P(label, PSTR("+ OR")); break;
+        case 'x': strcpy_P(label, PSTR("* AND")); break;
+        case '-': strcpy_P(label, PSTR("- XOR")); break;
+        case '/': strcpy_P(label, PSTR("/ NOT")); break;
+        case '<': strcpy_P(label, PSTR("<< >>")); break;
+        case '\n': strcpy_P(label, PSTR("=")); break;
+        default:
+            label[0] = input;
+            label[1] = this->base == 16 && input >= '1' && input <= '6' ? 'A' + input - '1' : 0;
+            label[2] = 0;
+            break;
+    }
+
+    return true;
+}
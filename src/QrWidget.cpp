@@ -0,0 +1,42 @@
+#include "QrWidget.hpp"
+#include "Features.h"
+
+#if FEATURE_QR
+#include "qrcode.h"
+
+// modules of the largest version, rounded up to whole bytes
+#define QR_BUFFER_SIZE (((4 * QR_MAX_VERSION + 17) * (4 * QR_MAX_VERSION + 17) + 7) / 8)
+
+unsigned char qr_draw(U8G2* u8g2, const unsigned char x, const unsigned char y, const char* text) {
+    QRCode qrcode;
+    unsigned char modules[QR_BUFFER_SIZE];
+
+    // the smallest version that holds the text has the biggest modules to scan
+    unsigned char version = 1;
+
+    while (version <= QR_MAX_VERSION && qrcode_initText(&qrcode, modules, version, ECC_LOW, text) != 0) {
+        version++;
+    }
+
+    if (version > QR_MAX_VERSION) {
+        return 0;
+    }
+
+    // light background, a scanner needs the contrast around the code too
+    u8g2->setDrawColor(1);
+    u8g2->drawBox(x, y, qrcode.size + 2, qrcode.size + 2);
+    u8g2->setDrawColor(0);
+
+    for (unsigned char row = 0; row < qrcode.size; row++) {
+        for (unsigned char col = 0; col < qrcode.size; col++) {
+            if (qrcode_getModule(&qrcode, col, row)) {
+                u8g2->drawPixel(x + 1 + col, y + 1 + row);
+            }
+        }
+    }
+
+    u8g2->setDrawColor(1);
+
+    return qrcode.size + 2;
+}
+#endif
@@ -0,0 +1,47 @@
+#include "Reset.hpp"
+#include "Log.hpp"
+#include <avr/wdt.h>
+
+// not cleared by the startup code, so it still holds what was there before
+// the reset, or garbage after a power cycle
+static unsigned short resetMarker __attribute__((section(".noinit")));
+
+void reboot() {
+    Log.println(F("Rebooting"));
+    Log.process();
+
+    reset_mark(RESET_MARK_SOFT);
+
+    // also clears the interrupt enable, so this isn't saved as a crash
+    wdt_enable(WDTO_15MS);
+    while (true);
+}
+
+void reset_mark(const unsigned short mark) {
+    resetMarker = mark;
+}
+
+EResetReason reset_read() {
+    const unsigned short marker = resetMarker;
+
+    resetMarker = 0;
+
+    if (marker == RESET_MARK_WATCHDOG) {
+        return RESET_WATCHDOG;
+    } else if (marker == RESET_MARK_SOFT) {
+        return RESET_SOFT;
+    }
+
+    return RESET_UNKNOWN;
+}
+
+const __FlashStringHelper* reset_name(const EResetReason reason) {
+    switch (reason) {
+        case RESET_WATCHDOG:
+            return F("watchdog");
+        case RESET_SOFT:
+            return F("reboot");
+        default:
+            return F("power on or reset pin");
+    }
+}
@@ -0,0 +1,183 @@
+#include "RpnCalculator.hpp"
+#include "Format.hpp"
+#include "Arduino.h"
+#include <math.h>
+
+unsigned char RpnCalculator::getChar(unsigned char row, unsigned char column) const {
+    return this->mapping[row][column];
+}
+
+void RpnCalculator::push() {
+    this->t = this->z;
+    this->z = this->y;
+    this->y = this->x;
+}
+
+void RpnCalculator::drop() {
+    this->y = this->z;
+    this->z = this->t;
+}
+
+void RpnCalculator::finishEntry() {
+    if (this->entering) {
+        this->x = atof(this->input);
+        this->entering = false;
+    }
+}
+
+void RpnCalculator::onPress(char row, char column) {
+    const unsigned char input = this->getChar(row, column);
+
+    this->drawNext = true;
+
+    if ((input >= '0' && input <= '9') || input == '.') {
+        this->doNumeric(input);
+    } else {
+        this->doOperation(input);
+    }
+}
+
+void RpnCalculator::doNumeric(const char input) {
+    if (!this->entering) {
+        if (this->lift) {
+            this->push();
+        }
+
+        this->input[0] = 0;
+        this->entering = true;
+        this->error = false;
+    }
+
+    const unsigned char length = strlen(this->input);
+
+    if (length >= CALC_VALUE_SIZE || (input == '.' && strchr(this->input, '.') != nullptr)) {
+        return;
+    }
+
+    this->input[length] = input;
+    this->input[length + 1] = 0;
+}
+
+void RpnCalculator::doOperation(const char op) {
+    this->finishEntry();
+
+    switch (op) {
+        case '\n':
+            this->push();
+            this->lift = false;
+            return;
+        case 'C':
+            this->x = 0;
+            this->error = false;
+            this->lift = false;
+            return;
+        case 's': {
+            const double swapped = this->x;
+            this->x = this->y;
+            this->y = swapped;
+            break;
+        }
+        case 'r': {
+            // roll down, x goes to the top
+            const double bottom = this->x;
+            this->x = this->y;
+            this->drop();
+            this->t = bottom;
+            break;
+        }
+        case 'n':
+            this->x = -this->x;
+            break;
+        case 'b':
+            // drop x
+            this->x = this->y;
+            this->drop();
+            break;
+        case '+':
+        case '-':
+        case 'x':
+        case '/': {
+            double output;
+
+            switch (op) {
+                case '+': output = this->y + this->x; break;
+                case '-': output = this->y - this->x; break;
+                case 'x': output = this->y * this->x; break;
+                default: output = this->y / this->x; break;
+            }
+
+            // leave the stack as it was so the operands can be fixed
+            this->error = isinf(output) || isnan(output);
+
+            if (this->error) {
+                return;
+            }
+
+            this->x = output;
+            this->drop();
+            break;
+        }
+        default:
+            return;
+    }
+
+    this->lift = true;
+}
+
+void RpnCalculator::draw(U8G2* u8g2) {
+    if (!this->drawNext) return;
+    this->drawNext = false;
+
+    char value[CALC_VALUE_SIZE + 1];
+
+    u8g2->clearBuffer();
+    u8g2->setFont(u8g2_font_5x8_tr);
+
+    u8g2->drawStr(0, 8, "RPN");
+
+    if (this->error) {
+        u8g2->drawStr(20, 8, "E");
+    }
+
+    format_double(value, sizeof(value), this->y, CALC_PRECISION);
+    u8g2->drawStr(128 - u8g2->getStrWidth(value), 8, value);
+
+    if (!this->entering) {
+        format_double(value, sizeof(value), this->x, CALC_PRECISION);
+    }
+
+    const char* shown = this->entering ? this->input : value;
+
+    u8g2->setFont(u8g2_font_ncenB14_tr);
+
+    if (u8g2->getStrWidth(shown) > 128) {
+        u8g2->setFont(u8g2_font_ncenB08_tr);
+    }
+
+    u8g2->drawStr(128 - u8g2->getStrWidth(shown), 31, shown);
+
+    u8g2->sendBuffer();
+}
+
+void RpnCalculator::onShow() {
+    this->drawNext = true;
+}
+
+bool RpnCalculator::describeKey(char row, char column, char* label) const {
+    const unsigned char input = this->getChar(row, column);
+
+    switch (input) {
+        case 's': strcpy_P(label, PSTR("x<>y")); break;
+        case 'r': strcpy_P(label, PSTR("Roll")); break;
+        case 'n': strcpy_P(label, PSTR("+/-")); break;
+        case 'b': strcpy_P(label, PSTR("Drop")); break;
+        case 'C': strcpy_P(label, PSTR("Clear")); break;
+        case '\n': strcpy_P(label, PSTR("Enter")); break;
+        default:
+            label[0] = input;
+            label[1] = 0;
+            break;
+    }
+
+    return true;
+}
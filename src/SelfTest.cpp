@@ -0,0 +1,124 @@
+#include "SelfTest.hpp"
+#include "Hotkeys.hpp"
+#include "Log.hpp"
+
+void SelfTest::run(const unsigned char* rowPins, const unsigned char* colPins) {
+    unsigned long pressed = 0;
+    unsigned char count = 0;
+
+    // every row is high, nothing should pull a column low
+    for (unsigned char col = 0; col < COLS; col++) {
+        if (digitalRead(colPins[col]) == LOW) {
+            this->stuckColumns |= 1 << col;
+        }
+    }
+
+    for (unsigned char row = 0; row < ROWS; row++) {
+        digitalWrite(rowPins[row], LOW);
+
+        for (unsigned char col = 0; col < COLS; col++) {
+            if (digitalRead(colPins[col]) == LOW || (this->stuckColumns & (1 << col))) {
+                pressed |= 1UL << KEY_ID(row, col);
+                count++;
+            }
+        }
+
+        digitalWrite(rowPins[row], HIGH);
+    }
+
+    this->held = NO_KEY;
+
+    if (count == 1) {
+        // somebody holding a key on purpose
+        for (unsigned char key = 0; key < ROWS * COLS; key++) {
+            if (pressed & (1UL << key)) {
+                this->held = key;
+            }
+        }
+    } else if (count > 1) {
+        this->suspects = pressed;
+    }
+
+    for (unsigned char key = 0; key < ROWS * COLS; key++) {
+        if (this->suspects & (1UL << key)) {
+            Log.print(F("Suspect key [")); Log.print(key / COLS, DEC);
+            Log.print(F("][")); Log.print(key % COLS, DEC); Log.println(']');
+            Log.process();
+        }
+    }
+}
+
+bool SelfTest::hasFaults() const {
+    return this->suspects != 0;
+}
+
+unsigned char SelfTest::heldKey() const {
+    return this->held;
+}
+
+bool SelfTest::isMasked(const unsigned char key, const bool raw) {
+    const unsigned long bit = 1UL << key;
+
+    if (!(this->suspects & bit)) {
+        return false;
+    }
+
+    if (!raw) {
+        this->suspects &= ~bit;
+    }
+
+    return true;
+}
+
+void SelfTest::onPress(char row, char column) {
+    this->done = true;
+}
+
+void SelfTest::draw(U8G2* u8g2) {
+    if (!this->drawNext) return;
+    this->drawNext = false;
+
+    // "r1c2" per key, as many as fit on two lines
+    char line[2][26] = {"", ""};
+    unsigned char lineIndex = 0;
+
+    for (unsigned char key = 0; key < ROWS * COLS && lineIndex < 2; key++) {
+        if (!(this->suspects & (1UL << key))) {
+            continue;
+        }
+
+        char* text = line[lineIndex];
+        const unsigned char length = strlen(text);
+        const char position[] = {'r', (char)('0' + key / COLS), 'c', (char)('0' + key % COLS), ' ', 0};
+
+        if (length + sizeof(position) > sizeof(line[0])) {
+            lineIndex++;
+            key--;
+            continue;
+        }
+
+        strcat(text, position);
+    }
+
+    u8g2->clearBuffer();
+    u8g2->setFont(u8g2_font_ncenB08_tr);
+    u8g2->drawStr(0, 10, this->stuckColumns ? "Stuck column" : "Keys stuck at boot");
+    u8g2->setFont(u8g2_font_5x8_tr);
+    u8g2->drawStr(0, 21, line[0]);
+    u8g2->drawStr(0, 31, line[1]);
+    u8g2->sendBuffer();
+}
+
+void SelfTest::tick(const unsigned long ms) {
+    if (this->start == 0) {
+        this->start = ms;
+    }
+
+    if (ms - this->start >= SELF_TEST_WARNING_MS) {
+        this->done = true;
+    }
+}
+
+bool SelfTest::isDone() const {
+    return this->done;
+}
@@ -0,0 +1,128 @@
+#include "Sequence.hpp"
+#include "HidOutput.hpp"
+#include "Format.hpp"
+
+// step sizes cycled through with multiply
+const long sequenceSteps[] = {1, 2, 5, 10, 100};
+
+#define SEQUENCE_STEP_COUNT (sizeof(sequenceSteps) / sizeof(sequenceSteps[0]))
+
+// zero padding cycled through with divide
+#define SEQUENCE_MAX_PADDING 6
+
+const char sequenceSuffixNames[SEQUENCE_SUFFIX_COUNT][6] PROGMEM = {"", "Tab", "Enter"};
+
+unsigned char Sequence::getChar(unsigned char row, unsigned char column) const {
+    return this->mapping[row][column];
+}
+
+void Sequence::type() {
+    char text[SEQUENCE_MAX_PADDING + SEQUENCE_DIGITS + 2];
+
+    hid.print(format_int(text, sizeof(text), this->next, this->padding, '0'));
+
+    if (this->suffix == SEQUENCE_SUFFIX_TAB) {
+        hid.write('\t');
+    } else if (this->suffix == SEQUENCE_SUFFIX_ENTER) {
+        hid.write('\n');
+    }
+
+    this->next += this->step;
+    this->editing = 0;
+}
+
+void Sequence::onPress(char row, char column) {
+    const unsigned char input = this->getChar(row, column);
+
+    this->drawNext = true;
+
+    if (input >= '0' && input <= '9') {
+        if (this->editing == 0) {
+            this->next = 0;
+        }
+
+        if (this->editing < SEQUENCE_DIGITS) {
+            this->editing++;
+            this->next = this->next * 10 + (input - '0');
+            this->start = this->next;
+        }
+
+        return;
+    }
+
+    switch (input) {
+        case '\n':
+            this->type();
+            break;
+        case '-':
+            // take back the last one, e.g. after a misprint
+            this->next = max(0L, this->next - this->step);
+            this->editing = 0;
+            break;
+        case 'C':
+            this->next = this->start;
+            this->editing = 0;
+            break;
+        case 'i': {
+            unsigned char i = 0;
+
+            while (i < SEQUENCE_STEP_COUNT && sequenceSteps[i] <= this->step) i++;
+
+            this->step = sequenceSteps[i % SEQUENCE_STEP_COUNT];
+            break;
+        }
+        case 'p':
+            this->padding = this->padding >= SEQUENCE_MAX_PADDING ? 0 : max(2, this->padding + 1);
+            break;
+        case 's':
+            this->suffix = (this->suffix + 1) % SEQUENCE_SUFFIX_COUNT;
+            break;
+    }
+}
+
+void Sequence::draw(U8G2* u8g2) {
+    if (!this->drawNext) return;
+    this->drawNext = false;
+
+    char value[SEQUENCE_MAX_PADDING + SEQUENCE_DIGITS + 2];
+
+    u8g2->clearBuffer();
+    u8g2->setFont(u8g2_font_5x8_tr);
+
+    u8g2->drawStr(0, 8, this->editing > 0 ? "From" : "Next");
+    u8g2->drawStr(30, 8, format_unit(value, sizeof(value), this->step, this->step == 1 ? " step" : " steps"));
+    char suffix[sizeof(sequenceSuffixNames[0])];
+
+    strcpy_P(suffix, sequenceSuffixNames[this->suffix]);
+    u8g2->drawStr(100, 8, suffix);
+
+    u8g2->setFont(u8g2_font_ncenB14_tr);
+    u8g2->drawStr(0, 30, format_int(value, sizeof(value), this->next, this->padding, '0'));
+
+    u8g2->sendBuffer();
+}
+
+bool Sequence::describeKey(char row, char column, char* label) const {
+    const unsigned char input = this->getChar(row, column);
+
+    switch (input) {
+        case 0: return false;
+        case 's': strcpy_P(label, PSTR("Suffix")); break;
+        case 'C': strcpy_P(label, PSTR("Restart")); break;
+        case 'p': strcpy_P(label, PSTR("Pad")); break;
+        case 'i': strcpy_P(label, PSTR("Step")); break;
+        case '-': strcpy_P(label, PSTR("Back")); break;
+        case '\n': strcpy_P(label, PSTR("Type")); break;
+        default:
+            label[0] = input;
+            label[1] = 0;
+            break;
+    }
+
+    return true;
+}
+
+void Sequence::onShow() {
+    this->drawNext = true;
+    this->editing = 0;
+}
@@ -0,0 +1,110 @@
+#include "Snippets.hpp"
+#include "HidOutput.hpp"
+
+#ifdef SNIPPET_CONFIG
+#include SNIPPET_CONFIG
+#else
+#include "SnippetConfig.h"
+#endif
+
+#define SNIPPET_COUNT (sizeof(snippetTable) / sizeof(snippetTable[0]))
+
+// characters of a snippet shown on screen
+#define SNIPPET_PREVIEW 20
+
+unsigned char Snippets::getSnippet(unsigned char row, unsigned char column) const {
+    return this->mapping[row][column];
+}
+
+void Snippets::onPress(char row, char column) {
+    const unsigned char number = this->getSnippet(row, column);
+
+    this->drawNext = true;
+
+    // a key while typing only stops it
+    if (this->typing != nullptr) {
+        this->typing = nullptr;
+        return;
+    }
+
+    if (number == 0 || number > SNIPPET_COUNT) {
+        return;
+    }
+
+    this->selected = number;
+    this->typing = (const char*)pgm_read_ptr(&snippetTable[number - 1]);
+}
+
+void Snippets::draw(U8G2* u8g2) {
+    if (!this->drawNext) return;
+    this->drawNext = false;
+
+    u8g2->clearBuffer();
+    u8g2->setFont(u8g2_font_ncenB08_tr);
+
+    if (this->selected == 0) {
+        u8g2->drawStr(0, 10, "Snippets");
+        u8g2->setFont(u8g2_font_5x8_tr);
+        u8g2->drawStr(0, 28, "Press 1 to 0 to type");
+    } else {
+        char preview[SNIPPET_PREVIEW + 1];
+        const char* text = (const char*)pgm_read_ptr(&snippetTable[this->selected - 1]);
+        const char number[] = {(char)('0' + this->selected % 10), 0};
+
+        strncpy_P(preview, text, SNIPPET_PREVIEW);
+        preview[SNIPPET_PREVIEW] = 0;
+
+        // line breaks would show up as boxes
+        for (char* c = preview; *c != 0; c++) {
+            if (*c == '\n') *c = ' ';
+        }
+
+        u8g2->drawStr(0, 10, this->typing != nullptr ? "Typing" : "Typed");
+        u8g2->drawStr(60, 10, number);
+        u8g2->setFont(u8g2_font_5x8_tr);
+        u8g2->drawStr(0, 28, preview);
+    }
+
+    u8g2->sendBuffer();
+}
+
+void Snippets::tick(const unsigned long ms) {
+    // one character at a time, hid paces them
+    if (this->typing == nullptr || hid.isTyping()) {
+        return;
+    }
+
+    const char character = pgm_read_byte(this->typing);
+
+    if (character == 0) {
+        this->typing = nullptr;
+        this->drawNext = true;
+        return;
+    }
+
+    hid.write(character);
+    this->typing++;
+}
+
+void Snippets::onShow() {
+    this->drawNext = true;
+    this->selected = 0;
+}
+
+bool Snippets::describeKey(char row, char column, char* label) const {
+    const unsigned char number = this->getSnippet(row, column);
+
+    if (number == 0 || number > SNIPPET_COUNT) {
+        return false;
+    }
+
+    strcpy_P(label, PSTR("Type "));
+    label[5] = '0' + number % 10;
+    label[6] = 0;
+
+    return true;
+}
+
+void Snippets::onHide() {
+    this->typing = nullptr;
+}
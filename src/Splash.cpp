@@ -0,0 +1,63 @@
+#include "Splash.hpp"
+#include "Version.h"
+
+#define SPLASH_TITLE "NumCal"
+#define SPLASH_INFO FIRMWARE_VERSION " " __DATE__
+
+void Splash::onPress(char row, char column) {
+    this->done = true;
+}
+
+void Splash::draw(U8G2* u8g2) {
+    if (!this->drawNext) return;
+    this->drawNext = false;
+
+    u8g2->clearBuffer();
+    u8g2->setFont(u8g2_font_ncenB14_tr);
+
+    const unsigned char width = u8g2->getStrWidth(SPLASH_TITLE);
+    const unsigned char target = 64 - width / 2;
+
+    if (this->sliding) {
+        // the title comes in from the right behind a scan line
+        const unsigned char x = 128 - ((128 - target) * this->elapsed) / SPLASH_SLIDE_MS;
+
+        u8g2->drawStr(x, 20, SPLASH_TITLE);
+        u8g2->drawVLine(x - 2, 0, 32);
+    } else {
+        u8g2->drawStr(target, 20, SPLASH_TITLE);
+
+        const char* info = this->notice != nullptr ? this->notice : SPLASH_INFO;
+
+        u8g2->setFont(u8g2_font_5x8_tr);
+        u8g2->drawStr(64 - u8g2->getStrWidth(info) / 2, 31, info);
+    }
+
+    u8g2->sendBuffer();
+}
+
+void Splash::tick(const unsigned long ms) {
+    if (this->start == 0) {
+        this->start = ms;
+    }
+
+    this->elapsed = ms - this->start;
+
+    const bool sliding = this->elapsed < SPLASH_SLIDE_MS;
+
+    // redraw every frame of the slide and once more when it's over
+    this->drawNext |= sliding || this->sliding;
+    this->sliding = sliding;
+
+    if (this->elapsed >= SPLASH_MS) {
+        this->done = true;
+    }
+}
+
+bool Splash::isDone() const {
+    return this->done;
+}
+
+void Splash::setNotice(const char* notice) {
+    this->notice = notice;
+}
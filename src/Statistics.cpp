@@ -0,0 +1,131 @@
+#include "Statistics.hpp"
+#include "Format.hpp"
+#include "Arduino.h"
+#include <math.h>
+
+unsigned char Statistics::getChar(unsigned char row, unsigned char column) const {
+    return this->mapping[row][column];
+}
+
+void Statistics::onPress(char row, char column) {
+    const unsigned char input = this->getChar(row, column);
+    const unsigned char length = strlen(this->input);
+
+    this->drawNext = true;
+
+    switch (input) {
+        case 'R':
+            this->reset();
+            break;
+        case 'C':
+            this->input[0] = 0;
+            break;
+        case 'n':
+        case 's':
+        case 'm':
+        case 'd':
+            this->shown = input;
+            break;
+        case '\n':
+            if (length > 0) {
+                this->add(atof(this->input));
+                this->input[0] = 0;
+            }
+            break;
+        case '.':
+            if (strchr(this->input, '.') != nullptr) {
+                break;
+            }
+            // fall through
+        default:
+            if (input != 0 && length < CALC_VALUE_SIZE) {
+                this->input[length] = input;
+                this->input[length + 1] = 0;
+            }
+            break;
+    }
+}
+
+void Statistics::add(const double value) {
+    this->count++;
+    this->sum += value;
+
+    const double delta = value - this->mean;
+    this->mean += delta / this->count;
+    this->squares += delta * (value - this->mean);
+}
+
+// sample standard deviation
+double Statistics::deviation() const {
+    return this->count > 1 ? sqrt(this->squares / (this->count - 1)) : 0;
+}
+
+void Statistics::draw(U8G2* u8g2) {
+    if (!this->drawNext) return;
+    this->drawNext = false;
+
+    char value[CALC_VALUE_SIZE + 1];
+    const char* label;
+
+    switch (this->shown) {
+        case 's':
+            label = "Sum";
+            format_double(value, sizeof(value), this->sum, CALC_PRECISION);
+            break;
+        case 'm':
+            label = "Mean";
+            format_double(value, sizeof(value), this->mean, CALC_PRECISION);
+            break;
+        case 'd':
+            label = "SD";
+            format_double(value, sizeof(value), this->deviation(), CALC_PRECISION);
+            break;
+        default:
+            label = "n";
+            format_int(value, sizeof(value), this->count);
+            break;
+    }
+
+    u8g2->clearBuffer();
+    u8g2->setFont(u8g2_font_ncenB08_tr);
+
+    u8g2->drawStr(0, 10, "Stats");
+    u8g2->drawStr(126 - u8g2->getStrWidth(this->input), 10, this->input);
+    u8g2->drawStr(0, 31, label);
+    u8g2->drawStr(126 - u8g2->getStrWidth(value), 31, value);
+
+    u8g2->sendBuffer();
+}
+
+void Statistics::onShow() {
+    this->drawNext = true;
+}
+
+bool Statistics::describeKey(char row, char column, char* label) const {
+    const unsigned char input = this->getChar(row, column);
+
+    switch (input) {
+        case 0: return false;
+        case 'R': strcpy_P(label, PSTR("Reset")); break;
+        case 'C': strcpy_P(label, PSTR("Clear")); break;
+        case 'n': strcpy_P(label, PSTR("Count")); break;
+        case 's': strcpy_P(label, PSTR("Sum")); break;
+        case 'm': strcpy_P(label, PSTR("Mean")); break;
+        case 'd': strcpy_P(label, PSTR("StdDev")); break;
+        case '\n': strcpy_P(label, PSTR("Add")); break;
+        default:
+            label[0] = input;
+            label[1] = 0;
+            break;
+    }
+
+    return true;
+}
+
+void Statistics::reset() {
+    this->count = 0;
+    this->sum = 0;
+    this->mean = 0;
+    this->squares = 0;
+    this->input[0] = 0;
+}
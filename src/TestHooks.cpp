@@ -0,0 +1,86 @@
+#include "TestHooks.hpp"
+
+#if FEATURE_TEST_HOOKS
+TestHooks testHooks;
+
+#define TEST_HID_DOWN 1
+#define TEST_HID_CHARACTER 2
+
+void TestHooks::begin(U8G2* display) {
+    this->display = display;
+}
+
+void TestHooks::press(const unsigned char key) {
+    this->down |= 1UL << key;
+    this->tapping &= ~(1UL << key);
+}
+
+void TestHooks::release(const unsigned char key) {
+    this->down &= ~(1UL << key);
+    this->tapping &= ~(1UL << key);
+}
+
+void TestHooks::tap(const unsigned char key) {
+    this->down |= 1UL << key;
+    this->tapping |= 1UL << key;
+    this->tapStart = millis();
+}
+
+bool TestHooks::isDown(const unsigned char key, const unsigned long time) {
+    if (this->tapping != 0 && time - this->tapStart >= TEST_TAP_MS) {
+        this->down &= ~this->tapping;
+        this->tapping = 0;
+    }
+
+    return this->down & (1UL << key);
+}
+
+void TestHooks::report(const unsigned char key, const bool down, const bool character) {
+    this->reports[this->next].key = key;
+    this->reports[this->next].flags = (down ? TEST_HID_DOWN : 0) | (character ? TEST_HID_CHARACTER : 0);
+
+    this->next = (this->next + 1) % TEST_HID_HISTORY;
+
+    if (this->count < TEST_HID_HISTORY) {
+        this->count++;
+    }
+}
+
+void TestHooks::printScreen(Print* out) const {
+    if (this->display == nullptr) {
+        return;
+    }
+
+    const uint8_t* buffer = this->display->getBufferPtr();
+    const unsigned short size = this->display->getBufferTileWidth() * this->display->getBufferTileHeight() * 8;
+
+    // 32 bit FNV-1a, as for the PIN
+    unsigned long hash = 2166136261UL;
+
+    for (unsigned short i = 0; i < size; i++) {
+        hash ^= buffer[i];
+        hash *= 16777619UL;
+    }
+
+    out->print(F("screen "));
+    out->println(hash, HEX);
+}
+
+void TestHooks::printReports(Print* out) {
+    for (unsigned char i = 0; i < this->count; i++) {
+        const TestHidEntry* entry = &this->reports[(this->next + TEST_HID_HISTORY - this->count + i) % TEST_HID_HISTORY];
+
+        if (entry->flags & TEST_HID_CHARACTER) {
+            out->print(F("type "));
+        } else {
+            out->print(entry->flags & TEST_HID_DOWN ? F("down ") : F("up "));
+        }
+
+        out->print(F("0x"));
+        out->println(entry->key, HEX);
+    }
+
+    out->println(F("end"));
+    this->count = 0;
+}
+#endif
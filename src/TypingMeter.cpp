@@ -0,0 +1,95 @@
+#include "TypingMeter.hpp"
+#include "Format.hpp"
+
+// keys pressed in the last TYPING_BUCKETS * TYPING_BUCKET_MS, a minute
+unsigned int TypingMeter::rate() const {
+    unsigned int total = 0;
+
+    for (unsigned char i = 0; i < TYPING_BUCKETS; i++) {
+        total += this->buckets[i];
+    }
+
+    return total;
+}
+
+void TypingMeter::advance(const unsigned long ms) {
+    if (ms - this->bucketStart >= (unsigned long)TYPING_BUCKETS * TYPING_BUCKET_MS) {
+        // idle for the whole window
+        memset(this->buckets, 0, sizeof(this->buckets));
+        this->bucketStart = ms;
+        this->drawNext = true;
+        return;
+    }
+
+    while (ms - this->bucketStart >= TYPING_BUCKET_MS) {
+        this->bucketStart += TYPING_BUCKET_MS;
+        this->bucket = (this->bucket + 1) % TYPING_BUCKETS;
+        this->buckets[this->bucket] = 0;
+        this->drawNext = true;
+    }
+}
+
+void TypingMeter::onPress(char row, char column) {
+    Numpad::onPress(row, column);
+
+    this->advance(millis());
+
+    if (this->buckets[this->bucket] < 255) {
+        this->buckets[this->bucket]++;
+    }
+
+    const unsigned int current = this->rate();
+
+    if (current > this->peak) {
+        this->peak = current;
+    }
+
+    this->drawNext = true;
+}
+
+void TypingMeter::draw(U8G2* u8g2) {
+    if (!this->drawNext) return;
+    this->drawNext = false;
+
+    char value[8];
+    const unsigned int current = this->rate();
+
+    u8g2->clearBuffer();
+    u8g2->setFont(u8g2_font_ncenB08_tr);
+
+    u8g2->drawStr(0, 10, format_unit(value, sizeof(value), current, " KPM"));
+    u8g2->drawStr(0, 21, format_unit(value, sizeof(value), current / 5, " WPM"));
+    u8g2->drawStr(0, 32, "Peak");
+    u8g2->drawStr(30, 32, format_int(value, sizeof(value), this->peak));
+
+    // oldest bucket on the left, scaled to the busiest one
+    unsigned char highest = 1;
+
+    for (unsigned char i = 0; i < TYPING_BUCKETS; i++) {
+        highest = max(highest, this->buckets[i]);
+    }
+
+    for (unsigned char i = 0; i < TYPING_BUCKETS; i++) {
+        const unsigned char count = this->buckets[(this->bucket + 1 + i) % TYPING_BUCKETS];
+        const unsigned char height = (unsigned int)count * 32 / highest;
+
+        if (height > 0) {
+            u8g2->drawBox(68 + i * 3, 32 - height, 2, height);
+        }
+    }
+
+    u8g2->sendBuffer();
+}
+
+void TypingMeter::tick(const unsigned long ms) {
+    Numpad::tick(ms);
+    this->advance(ms);
+}
+
+void TypingMeter::onShow() {
+    Numpad::onShow();
+
+    memset(this->buckets, 0, sizeof(this->buckets));
+    this->bucketStart = millis();
+    this->peak = 0;
+}
@@ -0,0 +1,74 @@
+#include "UartBridge.hpp"
+#include "Format.hpp"
+#include "Arduino.h"
+#include "Features.h"
+
+#if FEATURE_UART_BRIDGE
+const unsigned long bridgeBauds[] = {115200, 57600, 38400, 19200, 9600};
+
+#define BRIDGE_BAUDS (sizeof(bridgeBauds) / sizeof(bridgeBauds[0]))
+
+// most bytes moved each way per loop, so a busy line can't starve the rest
+#define BRIDGE_CHUNK 64
+
+unsigned long UartBridge::baud() const {
+    return bridgeBauds[this->baudIndex];
+}
+
+// any key moves on to the next baud rate
+void UartBridge::onPress(char row, char column) {
+    this->baudIndex = (this->baudIndex + 1) % BRIDGE_BAUDS;
+
+    Serial1.end();
+    Serial1.begin(this->baud());
+
+    this->drawNext = true;
+}
+
+void UartBridge::draw(U8G2* u8g2) {
+    if (!this->drawNext) return;
+    this->drawNext = false;
+
+    char value[12];
+
+    u8g2->clearBuffer();
+    u8g2->setFont(u8g2_font_ncenB08_tr);
+
+    u8g2->drawStr(0, 10, "UART");
+    u8g2->drawStr(60, 10, format_int(value, sizeof(value), this->baud()));
+    u8g2->drawStr(0, 21, "To UART");
+    u8g2->drawStr(60, 21, format_int(value, sizeof(value), this->sent));
+    u8g2->drawStr(0, 32, "To USB");
+    u8g2->drawStr(60, 32, format_int(value, sizeof(value), this->received));
+
+    u8g2->sendBuffer();
+}
+
+void UartBridge::tick(const unsigned long ms) {
+    for (unsigned char i = 0; i < BRIDGE_CHUNK && Serial.available() > 0 && Serial1.availableForWrite() > 0; i++) {
+        Serial1.write(Serial.read());
+        this->sent++;
+    }
+
+    for (unsigned char i = 0; i < BRIDGE_CHUNK && Serial1.available() > 0 && Serial.availableForWrite() > 0; i++) {
+        Serial.write(Serial1.read());
+        this->received++;
+    }
+
+    // the counters change too fast to redraw for every byte
+    if (ms - this->lastDraw >= 250) {
+        this->lastDraw = ms;
+        this->drawNext = true;
+    }
+}
+
+void UartBridge::onShow() {
+    this->drawNext = true;
+    Serial1.begin(this->baud());
+}
+
+void UartBridge::onHide() {
+    Serial1.end();
+}
+
+#endif
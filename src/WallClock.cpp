@@ -0,0 +1,51 @@
+#include "WallClock.hpp"
+#include "WallTime.hpp"
+
+const char wallClockWeekdays[][4] PROGMEM = {"Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"};
+
+void WallClock::draw(U8G2* u8g2) {
+    if (!this->drawNext) return;
+    this->drawNext = false;
+
+    u8g2->clearBuffer();
+
+    if (!walltime_is_set()) {
+        u8g2->setFont(u8g2_font_ncenB08_tr);
+        u8g2->drawStr(0, 10, "Time not set");
+        u8g2->setFont(u8g2_font_5x8_tr);
+        u8g2->drawStr(0, 24, "time $(date +%s)");
+        u8g2->drawStr(0, 32, "on the console");
+        u8g2->sendBuffer();
+        return;
+    }
+
+    char text[20];
+    WallTime time;
+
+    walltime_split(this->shown, &time);
+
+    u8g2->setFont(u8g2_font_logisoso22_tn);
+    walltime_format(text, sizeof(text), this->shown, WALLTIME_TIME);
+    u8g2->drawStr(64 - u8g2->getStrWidth(text) / 2, 23, text);
+
+    u8g2->setFont(u8g2_font_4x6_tr);
+    strcpy_P(text, wallClockWeekdays[time.weekday]);
+    u8g2->drawStr(0, 31, text);
+    walltime_format(text, sizeof(text), this->shown, WALLTIME_DATE);
+    u8g2->drawStr(128 - u8g2->getStrWidth(text), 31, text);
+
+    u8g2->sendBuffer();
+}
+
+void WallClock::tick(const unsigned long ms) {
+    const unsigned long now = walltime_now();
+
+    if (now != this->shown) {
+        this->shown = now;
+        this->drawNext = true;
+    }
+}
+
+void WallClock::onShow() {
+    this->drawNext = true;
+}
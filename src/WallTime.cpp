@@ -0,0 +1,96 @@
+#include "WallTime.hpp"
+#include "Format.hpp"
+
+static unsigned long baseEpoch = 0;
+static unsigned long baseMillis = 0;
+
+void walltime_set(const unsigned long epoch) {
+    baseEpoch = epoch;
+    baseMillis = millis();
+}
+
+bool walltime_is_set() {
+    return baseEpoch != 0;
+}
+
+void walltime_tick() {
+    if (baseEpoch == 0) {
+        return;
+    }
+
+    const unsigned long seconds = (millis() - baseMillis) / 1000;
+
+    baseEpoch += seconds;
+    baseMillis += seconds * 1000;
+}
+
+unsigned long walltime_now() {
+    if (baseEpoch == 0) {
+        return 0;
+    }
+
+    return baseEpoch + (millis() - baseMillis) / 1000;
+}
+
+void walltime_split(const unsigned long epoch, WallTime* time) {
+    unsigned long days = epoch / 86400;
+    const unsigned long seconds = epoch % 86400;
+
+    time->hour = seconds / 3600;
+    time->minute = (seconds / 60) % 60;
+    time->second = seconds % 60;
+    time->weekday = (days + 4) % 7; // 1970-01-01 was a thursday
+
+    // civil from days, shifted so years start in march and leap days come last
+    days += 719468;
+    const unsigned long era = days / 146097;
+    const unsigned long dayOfEra = days - era * 146097;
+    const unsigned long yearOfEra = (dayOfEra - dayOfEra / 1460 + dayOfEra / 36524 - dayOfEra / 146096) / 365;
+    const unsigned long dayOfYear = dayOfEra - (365 * yearOfEra + yearOfEra / 4 - yearOfEra / 100);
+    const unsigned char shiftedMonth = (5 * dayOfYear + 2) / 153;
+
+    time->day = dayOfYear - (153 * shiftedMonth + 2) / 5 + 1;
+    time->month = shiftedMonth < 10 ? shiftedMonth + 3 : shiftedMonth - 9;
+    time->year = yearOfEra + era * 400 + (time->month <= 2 ? 1 : 0);
+}
+
+char* walltime_format(char* buffer, const unsigned char size, const unsigned long epoch, const EWallTimeFormat format) {
+    WallTime time;
+    walltime_split(epoch, &time);
+
+    if (size < 20) {
+        buffer[0] = 0;
+        return buffer;
+    }
+
+    // each number's terminator is overwritten by the separator after it
+    format_int(buffer, 5, time.year, 4, '0');
+    buffer[4] = '-';
+    format_int(buffer + 5, 3, time.month, 2, '0');
+    buffer[7] = '-';
+    format_int(buffer + 8, 3, time.day, 2, '0');
+    buffer[10] = ' ';
+    format_int(buffer + 11, 3, time.hour, 2, '0');
+    buffer[13] = ':';
+    format_int(buffer + 14, 3, time.minute, 2, '0');
+    buffer[16] = ':';
+    format_int(buffer + 17, 3, time.second, 2, '0');
+
+    if (format == WALLTIME_DATE) {
+        buffer[10] = 0;
+    } else if (format == WALLTIME_TIME) {
+        memmove(buffer, buffer + 11, 9);
+    }
+
+    return buffer;
+}
+
+void walltime_print(Print* out) {
+    if (!walltime_is_set()) {
+        out->println(F("time not set"));
+        return;
+    }
+
+    char text[20];
+    out->println(walltime_format(text, sizeof(text), walltime_now()));
+}
@@ -1,9 +1,56 @@
 #include <Arduino.h>
 #include "HID-Project.h"
 #include "KeyboardConfig.h"
+#include "Board.h"
+#include "Features.h"
 #include "Numpad.hpp"
 #include "Calculator.hpp"
+#include "RpnCalculator.hpp"
+#include "ProgrammerCalculator.hpp"
+#include "LiveNumpad.hpp"
+#include "TypingMeter.hpp"
+#include "Sequence.hpp"
+#include "Pomodoro.hpp"
+#include "WallClock.hpp"
+#include "Countdown.hpp"
+#include "Dice.hpp"
+#include "Blocks.hpp"
+#include "Jiggler.hpp"
+#include "MediaKeys.hpp"
+#include "MouseKeys.hpp"
+#include "Converter.hpp"
+#include "Statistics.hpp"
+#include "UartBridge.hpp"
+#include "Snippets.hpp"
 #include "DinoGame.hpp"
+#include "Diagnostics.hpp"
+#include "Log.hpp"
+#include "Console.hpp"
+#include "CrashDump.hpp"
+#include "Profiler.hpp"
+#include "Memory.hpp"
+#include "Hotkeys.hpp"
+#include "Reset.hpp"
+#include "HidOutput.hpp"
+#include "Macros.hpp"
+#include "Splash.hpp"
+#include "PinLock.hpp"
+#include "WallTime.hpp"
+#include "Notifier.hpp"
+#include "Debounce.hpp"
+#include "SelfTest.hpp"
+
+#ifdef AUX_CONFIG
+#include AUX_CONFIG
+#else
+#include "AuxConfig.h"
+#endif
+#include "KeyTrace.hpp"
+#include "TestHooks.hpp"
+#include "Version.h"
+#include "EepromLayout.h"
+#include <avr/wdt.h>
+#include <EEPROM.h>
 
 #include <U8g2lib.h>
 #ifdef U8X8_HAVE_HW_SPI
@@ -11,24 +58,157 @@
 #endif
 
 // U8G2_SSD1305_128X32_ADAFRUIT_F_4W_HW_SPI u8g2(U8G2_R0, /* cs=*/ 10, /* dc=*/ 14, /* reset=*/ 3);
-U8G2_SSD1305_128X32_ADAFRUIT_F_4W_SW_SPI u8g2(U8G2_R0, /* clock=*/ 15, /* data=*/ 16, /* cs=*/ 10, /* dc=*/ 14, /* reset=*/ 3);
+U8G2_SSD1305_128X32_ADAFRUIT_F_4W_SW_SPI u8g2(U8G2_R0, PIN_DISPLAY_CLOCK, PIN_DISPLAY_DATA, PIN_DISPLAY_CS, PIN_DISPLAY_DC, PIN_DISPLAY_RESET);
 
 // paged
 // U8G2_SSD1305_128X32_ADAFRUIT_1_4W_SW_SPI u8g2(U8G2_R0, /* clock=*/ 15, /* data=*/ 16, /* cs=*/ 10, /* dc=*/ 14, /* reset=*/ 3);
 // U8G2_SSD1305_128X32_ADAFRUIT_1_4W_HW_SPI u8g2(U8G2_R0, /* cs=*/ 10, /* dc=*/ 14, /* reset=*/ 3);
 
-#define DEBUG
-
 Numpad numpad;
+#if FEATURE_CALCULATOR
 Calculator calculator;
-// DinoGame dinoGame;
+#endif
+#if FEATURE_RPN
+RpnCalculator rpnCalculator;
+#endif
+#if FEATURE_PROGRAMMER
+ProgrammerCalculator programmerCalculator;
+#endif
+#if FEATURE_LIVE_NUMPAD
+LiveNumpad liveNumpad(&calculator);
+#endif
+#if FEATURE_GAMES
+DinoGame dinoGame;
+#endif
+#if FEATURE_STATISTICS
+Statistics statistics;
+#endif
+#if FEATURE_TYPING_METER
+TypingMeter typingMeter;
+#endif
+#if FEATURE_SEQUENCE
+Sequence sequence;
+#endif
+#if FEATURE_POMODORO
+Pomodoro pomodoro;
+#endif
+#if FEATURE_CLOCK
+WallClock wallClock;
+#endif
+#if FEATURE_COUNTDOWN
+Countdown countdown;
+#endif
+#if FEATURE_DICE
+Dice dice;
+#endif
+#if FEATURE_BLOCKS
+Blocks blocks;
+#endif
+#if FEATURE_JIGGLER
+Jiggler jiggler;
+#endif
+#if FEATURE_MEDIA
+MediaKeys mediaKeys;
+#endif
+#if FEATURE_MOUSE
+MouseKeys mouseKeys;
+#endif
+#if FEATURE_CONVERTER
+Converter converter;
+#endif
+#if FEATURE_SNIPPETS
+Snippets snippets;
+#endif
+#if FEATURE_UART_BRIDGE
+UartBridge uartBridge;
+#endif
+#if FEATURE_DIAGNOSTICS
+Diagnostics diagnostics;
+#endif
+#if FEATURE_SPLASH
+Splash splash;
+#endif
 
-// KeyboardInterface* currentMode = &dinoGame;
-// KeyboardInterface* currentMode = &calculator;
-KeyboardInterface* currentMode = &numpad;
+// modes cycled through by long pressing num lock
+KeyboardInterface* modes[] = {
+  &numpad,
+#if FEATURE_CALCULATOR
+  &calculator,
+#endif
+#if FEATURE_RPN
+  &rpnCalculator,
+#endif
+#if FEATURE_PROGRAMMER
+  &programmerCalculator,
+#endif
+#if FEATURE_LIVE_NUMPAD
+  &liveNumpad,
+#endif
+#if FEATURE_STATISTICS
+  &statistics,
+#endif
+#if FEATURE_TYPING_METER
+  &typingMeter,
+#endif
+#if FEATURE_SEQUENCE
+  &sequence,
+#endif
+#if FEATURE_POMODORO
+  &pomodoro,
+#endif
+#if FEATURE_CLOCK
+  &wallClock,
+#endif
+#if FEATURE_COUNTDOWN
+  &countdown,
+#endif
+#if FEATURE_DICE
+  &dice,
+#endif
+#if FEATURE_BLOCKS
+  &blocks,
+#endif
+#if FEATURE_JIGGLER
+  &jiggler,
+#endif
+#if FEATURE_MEDIA
+  &mediaKeys,
+#endif
+#if FEATURE_MOUSE
+  &mouseKeys,
+#endif
+#if FEATURE_CONVERTER
+  &converter,
+#endif
+#if FEATURE_SNIPPETS
+  &snippets,
+#endif
+#if FEATURE_UART_BRIDGE
+  &uartBridge,
+#endif
+#if FEATURE_DIAGNOSTICS
+  &diagnostics,
+#endif
+};
+
+const uint8_t modeCount = sizeof(modes) / sizeof(modes[0]);
+uint8_t modeIndex = 0;
 
-const uint8_t colPins[COLS] = {A3, A2, A1, A0};
-const uint8_t rowPins[ROWS] = {9, 8, 7, 6, 5, 4};
+KeyboardInterface* currentMode = modes[0];
+
+#if FEATURE_CONSOLE
+Console console;
+#endif
+
+const uint8_t colPins[COLS] = BOARD_COL_PINS;
+const uint8_t rowPins[ROWS] = BOARD_ROW_PINS;
+
+#if BOARD_AUX_COUNT > 0
+const uint8_t auxPins[BOARD_AUX_COUNT] = BOARD_AUX_PINS;
+bool auxStates[BOARD_AUX_COUNT];
+
+static_assert(sizeof(auxButtons) / sizeof(auxButtons[0]) >= BOARD_AUX_COUNT, "Every aux pin needs a button in AuxConfig.h");
+#endif
 
 bool tick = false;
 
@@ -36,11 +216,277 @@ unsigned long states[COLS][ROWS];
 
 unsigned short longPressMs = 1000;
 
+// when a key last went down, for locking when idle
+unsigned long lastKeyTime = 0;
+
+Hotkeys hotkeys;
+
+Debounce debounce;
+
+SelfTest selfTest;
+
+enum EOverlay : uint8_t {
+  OVERLAY_COUNTDOWN = 1,
+  OVERLAY_RECORDING = 2,
+  OVERLAY_DISABLED = 4,
+  OVERLAY_NOTIFY = 8,
+  OVERLAY_HELP = 16,
+};
+
+// overlays drawn on top of the mode in the last loop
+uint8_t overlaysShown = 0;
+
+// cheat sheet of the current mode laid out like the keys, until the next key
+// press or mode change
+bool helpShown = false;
+
+bool drawHelp() {
+  if (!helpShown) {
+    return false;
+  }
+
+  char label[HELP_LABEL_SIZE];
+
+  u8g2.setDrawColor(0);
+  u8g2.drawBox(0, 0, 128, 32);
+  u8g2.setDrawColor(1);
+  u8g2.setFont(u8g2_font_4x6_tr);
+
+  for (uint8_t row = 0; row < ROWS; row++) {
+    for (uint8_t col = 0; col < COLS; col++) {
+      if (currentMode->describeKey(row, col, label)) {
+        u8g2.drawStr(col * 32, 5 + row * 5, label);
+      }
+    }
+  }
+
+  return true;
+}
+
+// banner while the kill switch holds back all output
+bool drawOutputDisabled() {
+  if (!hid.isBlocked(HID_BLOCK_KILL)) {
+    return false;
+  }
+
+  u8g2.setFont(u8g2_font_ncenB08_tr);
+  u8g2.setDrawColor(0);
+  u8g2.drawBox(8, 8, 112, 16);
+  u8g2.setDrawColor(1);
+  u8g2.drawFrame(8, 8, 112, 16);
+  u8g2.drawStr(64 - u8g2.getStrWidth("OUTPUT DISABLED") / 2, 20, "OUTPUT DISABLED");
+
+  return true;
+}
+
+void drawOverlays(const bool modeDrawn, const unsigned long time) {
+  uint8_t overlays = 0;
+
+  if (drawHelp()) overlays |= OVERLAY_HELP;
+  if (hotkeys.draw(&u8g2, time)) overlays |= OVERLAY_COUNTDOWN;
+  if (macros.draw(&u8g2)) overlays |= OVERLAY_RECORDING;
+  if (drawOutputDisabled()) overlays |= OVERLAY_DISABLED;
+  if (notifier.draw(&u8g2, time)) overlays |= OVERLAY_NOTIFY;
+
+  if (overlaysShown & ~overlays) {
+    // something went away, only a full redraw of the mode gets rid of it
+    currentMode->invalidate();
+  } else if (overlays != 0 && (modeDrawn || overlays != overlaysShown || (overlays & OVERLAY_COUNTDOWN))) {
+    // the countdown animates, the rest only needs sending when it changed
+    u8g2.sendBuffer();
+  }
+
+  overlaysShown = overlays;
+}
+
+void switchMode(KeyboardInterface* mode) {
+  currentMode->onHide();
+  currentMode = mode;
+  currentMode->onShow();
+  helpShown = false;
+  hotkeys.setTapChordsEnabled(currentMode->allowsTapChords());
+}
+
+// sends the OS shortcut that locks the screen
+void lockHost() {
+  #if HOST_PLATFORM == HOST_MACOS
+  const KeyboardKeycode keys[] = {KEY_LEFT_CTRL, KEY_LEFT_GUI, KEY_Q};
+  #else
+  const KeyboardKeycode keys[] = {KEY_LEFT_GUI, KEY_L};
+  #endif
+
+  for (const KeyboardKeycode key : keys) {
+    hid.press(key);
+  }
+
+  for (const KeyboardKeycode key : keys) {
+    hid.release(key);
+  }
+}
+
+// shows the lock screen until the PIN is typed, if there is one
+void lockKeypad() {
+  #if FEATURE_PIN_LOCK
+  if (!pinLock.isSet()) {
+    notifier.post(F("No PIN set"));
+    return;
+  }
+
+  pinLock.lock();
+  switchMode(&pinLock);
+  #endif
+}
+
+// taps a key of the current mode as if it was pressed a few times, so the
+// zero chords type KEYPAD_0 in numpad mode and add digits in the calculator
+void tapKey(const uint8_t row, const uint8_t col, const uint8_t times) {
+  for (uint8_t i = 0; i < times; i++) {
+    currentMode->onPress(row, col);
+    currentMode->onRelease(row, col);
+  }
+}
+
+void runHotkey(const EHotkeyAction action) {
+  switch (action) {
+    case HOTKEY_NEXT_MODE:
+      modeIndex = (modeIndex + 1) % modeCount;
+      switchMode(modes[modeIndex]);
+      EEPROM.update(ADDR_EEPROM_LAST_MODE, modeIndex);
+      break;
+    case HOTKEY_GAME_MODE:
+      #if FEATURE_GAMES
+      #ifdef DEBUG
+      Log.println(F("Dino Mode activated"));
+      #endif
+      switchMode(&dinoGame);
+      #endif
+      break;
+    case HOTKEY_LOCK_HOST:
+      lockHost();
+      break;
+    case HOTKEY_SLEEP_HOST:
+      hid.system(SYSTEM_SLEEP);
+      break;
+    case HOTKEY_TIMESTAMP:
+      if (walltime_is_set()) {
+        char text[20];
+        hid.print(walltime_format(text, sizeof(text), walltime_now(), TIMESTAMP_FORMAT));
+      } else {
+        notifier.post(F("Time not set"));
+      }
+      break;
+    case HOTKEY_HELP:
+      helpShown = !helpShown;
+      break;
+    case HOTKEY_LOCK_KEYPAD:
+      lockKeypad();
+      break;
+    case HOTKEY_REBOOT:
+      reboot();
+      break;
+    case HOTKEY_MACRO_RECORD:
+      if (macros.isRecording()) {
+        macros.stopRecording();
+      } else {
+        macros.startRecording();
+      }
+      break;
+    case HOTKEY_KILL_SWITCH:
+      if (hid.isBlocked(HID_BLOCK_KILL)) {
+        hid.unblock(HID_BLOCK_KILL);
+        Log.println(F("Output enabled"));
+      } else {
+        macros.stop();
+        hid.block(HID_BLOCK_KILL);
+        Log.println(F("Output disabled"));
+      }
+      break;
+    case HOTKEY_DOUBLE_ZERO:
+      tapKey(5, 0, 2);
+      break;
+    case HOTKEY_TRIPLE_ZERO:
+      tapKey(5, 0, 3);
+      break;
+    default:
+      if (action >= HOTKEY_MACRO_1 && action < HOTKEY_MACRO_1 + MACRO_SLOTS) {
+        const uint8_t slot = action - HOTKEY_MACRO_1;
+
+        if (macros.isRecording()) {
+          macros.stopRecording();
+          macros.save(slot);
+          notifier.post(F("Saved"));
+        } else {
+          macros.play(slot);
+        }
+      }
+      break;
+  }
+}
+
+// holding clear while powering up boots with defaults
+#define SAFE_MODE_KEY KEY_ID(1, 0)
+
+// the whole loop has to complete within this time or the board resets
+#define WATCHDOG_TIMEOUT WDTO_2S
+
+// the auxiliary buttons skip hotkeys and modes, they're either an action or
+// a plain key
+void scanAux(const unsigned long time) {
+  #if BOARD_AUX_COUNT > 0
+  for (uint8_t i = 0; i < BOARD_AUX_COUNT; i++) {
+    const uint8_t key = ROWS * COLS + i;
+    const bool raw = digitalRead(auxPins[i]) == LOW;
+    const bool now = debounce.update(key, raw, time);
+    TRACE_KEY(key, raw, now, time);
+
+    if (now == auxStates[i]) {
+      continue;
+    }
+
+    auxStates[i] = now;
+
+    const AuxButton* button = &auxButtons[i];
+
+    if (button->action == HOTKEY_NONE) {
+      if (now) {
+        hid.press(button->key);
+      } else {
+        hid.release(button->key);
+      }
+    } else if (now) {
+      #if FEATURE_PIN_LOCK
+      if (!pinLock.isLocked()) {
+        runHotkey(button->action);
+      }
+      #else
+      runHotkey(button->action);
+      #endif
+    }
+  }
+  #endif
+}
+
+// the tests in test/ bring their own
+#ifndef PIO_UNIT_TESTING
 void setup()
 {
+  memory_paint();
+
+  const EResetReason resetReason = reset_read();
+
   BootKeyboard.begin();
+  System.begin();
+  #if HID_MOUSE
+  Mouse.begin();
+  #endif
+  #if HID_CONSUMER
+  Consumer.begin();
+  #endif
   Serial.begin(9600);
   u8g2.begin();
+  #if FEATURE_TEST_HOOKS
+  testHooks.begin(&u8g2);
+  #endif
   
   u8g2.clearBuffer();	
   u8g2.sendBuffer();	
@@ -54,64 +500,158 @@ void setup()
     digitalWrite(pin, HIGH);
   }
 
-  Serial.println("Ready");
+  #if BOARD_AUX_COUNT > 0
+  for (uint8_t pin : auxPins) {
+    pinMode(pin, INPUT_PULLUP);
+  }
+  #endif
+
+  Log.println(F("NumCal " FIRMWARE_VERSION " ready"));
+  Log.print(F("Reset by ")); Log.println(reset_name(resetReason));
+  Log.process();
+
+  #ifdef DEBUG
+  memory_print(&Log);
+  Log.process();
+  Log.print(F("modes: numpad=")); Log.print(sizeof(numpad), DEC);
+  #if FEATURE_CALCULATOR
+  Log.print(F(" calculator=")); Log.print(sizeof(calculator), DEC);
+  #endif
+  Log.println();
+  #endif
+
+  #if FEATURE_CONSOLE
+  CrashRecord crash;
+  if (crashdump_load(&crash)) {
+    Log.println(F("Crash recorded, see \"crash\""));
+  }
+  #endif
+
+  selfTest.run(rowPins, colPins);
+
+  // holding a key while plugging in skips the splash, the key counts as
+  // already down so it doesn't get typed
+  const uint8_t bootKey = selfTest.heldKey();
+
+  if (bootKey != NO_KEY) {
+    Log.print(F("Boot key held [")); Log.print(bootKey / COLS, DEC);
+    Log.print(F("][")); Log.print(bootKey % COLS, DEC); Log.println(']');
+
+    states[bootKey % COLS][bootKey / COLS] = millis();
+    debounce.hold(bootKey);
+
+    // the safe mode key is clear, which held alone switches (and saves) modes
+    hotkeys.ignoreUntilReleased(bootKey);
+  }
+
+  if (bootKey == SAFE_MODE_KEY) {
+    Log.println(F("Safe mode, saved mode and macros are not loaded"));
+    macros.setEnabled(false);
+  } else {
+    const uint8_t lastMode = EEPROM.read(ADDR_EEPROM_LAST_MODE);
+
+    if (lastMode < modeCount) {
+      modeIndex = lastMode;
+      currentMode = modes[modeIndex];
+    }
+  }
+
+  #if FEATURE_SPLASH
+  if (bootKey == NO_KEY) {
+    currentMode = &splash;
+  }
+
+  if (resetReason == RESET_WATCHDOG) {
+    splash.setNotice("Recovered from a hang");
+  }
+  #endif
+
+  if (selfTest.hasFaults()) {
+    currentMode = &selfTest;
+  }
+
+  #if FEATURE_PIN_LOCK
+  if (pinLock.isSet()) {
+    pinLock.lock();
+    currentMode = &pinLock;
+  }
+  #endif
 
   currentMode->onShow();
+  hotkeys.setTapChordsEnabled(currentMode->allowsTapChords());
+
+  wdt_enable(WATCHDOG_TIMEOUT);
+  crashdump_begin();
 }
 
 void loop()
 {
   const unsigned long time = millis();
 
+  PROFILE_BEGIN(PROFILE_LOOP);
+  PROFILE_BEGIN(PROFILE_SCAN);
+
   for (uint8_t row = 0; row < ROWS; row++) {
     digitalWrite(rowPins[row], LOW);
 
     for (uint8_t col = 0; col < COLS; col++) {
       const unsigned long prev = states[col][row];
-      const bool now = digitalRead(colPins[col]) == LOW; // todo maybe needs to be flipped?
+      const bool raw = digitalRead(colPins[col]) == LOW || TEST_KEY_DOWN(KEY_ID(row, col), time); // todo maybe needs to be flipped?
+
+      // keys that read pressed at power-up stay quiet until they let go
+      if (selfTest.isMasked(KEY_ID(row, col), raw)) {
+        continue;
+      }
+
+      const bool now = debounce.update(KEY_ID(row, col), raw, time);
+      TRACE_KEY(KEY_ID(row, col), raw, now, time);
 
       if (now != (prev > 0)) {
         #ifdef DEBUG
-        Serial.print("["); Serial.print(row, DEC); Serial.print("]");
-        Serial.print("["); Serial.print(col, DEC); Serial.print("]");
-        Serial.println(now ? "DOWN" : "UP");
+        Log.print('['); Log.print(row, DEC); Log.print(']');
+        Log.print('['); Log.print(col, DEC); Log.print(']');
+        Log.println(now ? F("DOWN") : F("UP"));
         #endif
 
         if (now) {
           states[col][row] = time;
-          currentMode->onPress(row, col);
+          lastKeyTime = time;
+
+          if (macros.isPlaying()) {
+            // any key stops playback, in case it's typing into the wrong window
+            macros.stop();
+            notifier.post(F("Stopped"));
+          } else if (helpShown) {
+            // the key only closes the help
+            helpShown = false;
+          } else if (hotkeys.onPress(row, col, states)) {
+            currentMode->onPress(row, col);
+          }
         } else {
           states[col][row] = 0;
-          currentMode->onRelease(row, col);
+
+          switch (hotkeys.onRelease(row, col)) {
+            case HOTKEY_TAP:
+              currentMode->onPress(row, col);
+              currentMode->onRelease(row, col);
+              break;
+            case HOTKEY_PASS:
+              currentMode->onRelease(row, col);
+              break;
+            case HOTKEY_SWALLOW:
+              break;
+          }
         }
       } else if (now && (time - prev) >= longPressMs) {
         #ifdef DEBUG
-        Serial.print("["); Serial.print(row, DEC); Serial.print("]");
-        Serial.print("["); Serial.print(col, DEC); Serial.print("]");
-        Serial.println("LONG");
+        Log.print('['); Log.print(row, DEC); Log.print(']');
+        Log.print('['); Log.print(col, DEC); Log.print(']');
+        Log.println(F("LONG"));
         #endif
 
         states[col][row] = time; // reset
 
-        currentMode->onHide();
-
-        // bad way of doing this
-        // detect numlock and swich modes
-        if (col == 0 && row == 1) {
-          if (states[0][0] > 0) {
-            #ifdef DEBUG
-            Serial.println("Dino Mode activated");
-            #endif
-            // currentMode = &dinoGame;
-          } else if (currentMode == &numpad) {
-            // currentMode = &dinoGame;
-            currentMode = &calculator;
-          } else {
-            currentMode = &numpad;
-          }
-
-          currentMode->onShow();
-        } else {
+        if (!hotkeys.isActive(row, col)) {
           currentMode->onLongPress(row, col);
         }
       }
@@ -120,6 +660,89 @@ void loop()
     digitalWrite(rowPins[row], HIGH);
   }
 
+  scanAux(time);
+
+  PROFILE_END(PROFILE_SCAN);
+
+  const EHotkeyAction action = hotkeys.update(states, time);
+
+  #if FEATURE_PIN_LOCK
+  if (!pinLock.isLocked()) {
+    runHotkey(action);
+  }
+  #else
+  runHotkey(action);
+  #endif
+
+  PROFILE_BEGIN(PROFILE_TICK);
   currentMode->tick(time);
+  #if FEATURE_COUNTDOWN
+  if (currentMode != &countdown) {
+    countdown.tick(time);
+  }
+  #endif
+  hid.tick(time);
+  macros.tick(time);
+  walltime_tick();
+  notifier.tick(time);
+  PROFILE_END(PROFILE_TICK);
+
+  if (currentMode == &selfTest && selfTest.isDone()) {
+    switchMode(modes[modeIndex]);
+  }
+
+  #if FEATURE_SPLASH
+  if (currentMode == &splash && splash.isDone()) {
+    switchMode(modes[modeIndex]);
+  }
+  #endif
+
+  #if FEATURE_PIN_LOCK
+  if (currentMode == &pinLock && !pinLock.isLocked()) {
+    switchMode(modes[modeIndex]);
+  }
+
+  #if PIN_IDLE_MINUTES > 0
+  if (!pinLock.isLocked() && time - lastKeyTime >= PIN_IDLE_MINUTES * 60000UL && pinLock.isSet()) {
+    lockKeypad();
+  }
+  #endif
+  #endif
+
+  #if FEATURE_COUNTDOWN
+  // takes over from whatever regular mode is shown when the time is up
+  if (countdown.isRinging() && currentMode == modes[modeIndex] && currentMode != &countdown) {
+    switchMode(&countdown);
+  }
+  #endif
+
+  PROFILE_BEGIN(PROFILE_DRAW);
+  const bool modeDrawn = currentMode->needsDraw();
   currentMode->draw(&u8g2);
-}
\ No newline at end of file
+  drawOverlays(modeDrawn, time);
+  PROFILE_END(PROFILE_DRAW);
+
+  #if FEATURE_UART_BRIDGE
+  // the serial port belongs to the bridge while it's shown
+  const bool bridging = currentMode == &uartBridge;
+  #else
+  const bool bridging = false;
+  #endif
+
+  if (!bridging) {
+    #if FEATURE_CONSOLE
+    console.process();
+    #endif
+    Log.process();
+  }
+
+  PROFILE_END(PROFILE_LOOP);
+
+  // Only feed the watchdog after scanning, ticking and drawing all made it
+  // through. The 1200 baud touch used for uploading arms the watchdog to
+  // jump into the bootloader, so stop feeding once the host asks for that.
+  if (Serial.baud() != 1200) {
+    wdt_reset();
+  }
+}
+#endif
@@ -2,14 +2,37 @@
 #include <SPI.h>
 #include <Wire.h>
 #include "HID-Project.h"
-// #include "Calculator.hpp"
+#include "Calculator.hpp"
+#include "Format.hpp"
 #include <unity.h>
 
-// Calculator calc;
+// mode inputs, as Calculator::onPress(char) takes them after the key map
+static void feed(Calculator& calc, const char* keys) {
+    for (; *keys != 0; keys++) {
+        calc.onPress(*keys);
+    }
+}
+
+// a quick press of the key at row, column
+static void tap(Calculator& calc, const char row, const char column) {
+    calc.onPress(row, column);
+    calc.onRelease(row, column);
+}
+
+// a press of the key at row, column held through longPresses long presses
+static void hold(Calculator& calc, const char row, const char column, const unsigned char longPresses) {
+    calc.onPress(row, column);
+
+    for (unsigned char i = 0; i < longPresses; i++) {
+        calc.onLongPress(row, column);
+    }
+
+    calc.onRelease(row, column);
+}
 
 // set stuff up here
 void setUp(void) {
-    
+
 }
 
 // clean stuff up here
@@ -18,12 +41,296 @@ void tearDown(void) {
 }
 
 void test_calculator_numeric_input(void) {
-    // calc.onPress(1);
-    // calc.onPress(2);
-    // calc.onPress(3);
-    // calc.onPress(4);
+    Calculator calc;
+
+    feed(calc, "1234");
+
+    TEST_ASSERT_EQUAL_STRING("1234", calc.getInput());
+}
+
+void test_calculator_percent(void) {
+    Calculator calc;
+
+    feed(calc, "200+10%");
+    TEST_ASSERT_EQUAL_STRING("20", calc.getInput());
+    TEST_ASSERT_EQUAL_STRING("220", calc.getResult());
+
+    Calculator times;
+
+    feed(times, "50x20%");
+    TEST_ASSERT_EQUAL_STRING("10", times.getResult());
+
+    Calculator minus;
+
+    feed(minus, "200-25%");
+    TEST_ASSERT_EQUAL_STRING("150", minus.getResult());
+}
+
+void test_calculator_percent_long_press(void) {
+    Calculator calc;
+
+    feed(calc, "200+10");
+    hold(calc, 1, 1, 1);
+
+    TEST_ASSERT_EQUAL_STRING("220", calc.getResult());
+}
+
+void test_calculator_negate(void) {
+    Calculator calc;
+
+    feed(calc, "5N+3\n");
+    TEST_ASSERT_EQUAL_STRING("-2", calc.getResult());
+
+    Calculator twice;
+
+    feed(twice, "4x2NN\n");
+    TEST_ASSERT_EQUAL_STRING("8", twice.getResult());
+
+    Calculator result;
+
+    feed(result, "2+3\nN");
+    TEST_ASSERT_EQUAL_STRING("-5", result.getResult());
+}
+
+void test_calculator_repeat_equals(void) {
+    Calculator calc;
+
+    feed(calc, "2+3\n\n\n");
+    TEST_ASSERT_EQUAL_STRING("11", calc.getResult());
+
+    Calculator minus;
+
+    feed(minus, "10-2\n\n");
+    TEST_ASSERT_EQUAL_STRING("6", minus.getResult());
+
+    // a new number takes the place of the result
+    Calculator fresh;
+
+    feed(fresh, "2+3\n5\n");
+    TEST_ASSERT_EQUAL_STRING("8", fresh.getResult());
+}
+
+void test_calculator_undo(void) {
+    Calculator calc;
+
+    feed(calc, "12+Z-3\n");
+    TEST_ASSERT_EQUAL_STRING("9", calc.getResult());
+
+    Calculator digit;
+
+    feed(digit, "12+34Z\n");
+    TEST_ASSERT_EQUAL_STRING("15", digit.getResult());
+
+    Calculator equals;
+
+    feed(equals, "2+3\nZ");
+    TEST_ASSERT_EQUAL_STRING("2", equals.getResult());
+}
+
+void test_calculator_error_latch(void) {
+    Calculator calc;
+
+    // nothing but clear gets out of an error
+    feed(calc, "1/0\n5+2\n");
+    TEST_ASSERT_EQUAL_STRING("0", calc.getInput());
+
+    feed(calc, "C5+2\n");
+    TEST_ASSERT_EQUAL_STRING("7", calc.getResult());
+}
+
+void test_calculator_integer_division(void) {
+    Calculator calc;
+
+    feed(calc, "17\\5\n");
+    TEST_ASSERT_EQUAL_STRING("3", calc.getResult());
+
+    Calculator negative;
+
+    feed(negative, "0-17\\5\n");
+    TEST_ASSERT_EQUAL_STRING("-3", negative.getResult());
+
+    Calculator modulo;
+
+    feed(modulo, "17m5\n");
+    TEST_ASSERT_EQUAL_STRING("2", modulo.getResult());
+}
+
+void test_calculator_functions(void) {
+    Calculator calc;
+
+    feed(calc, "30S");
+    TEST_ASSERT_EQUAL_STRING("0.5", calc.getInput());
+
+    const char* const cases[][3] = {
+        {"60", "O", "0.5"},
+        {"45", "T", "1"},
+        {"180", "S", "0"},
+        {"100", "K", "2"},
+        {"1", "E", "2.7183"},
+        {"10", "L", "2.3026"},
+        {"2", "R", "1.4142"},
+        {"12", "Q", "144"},
+        {"8", "I", "0.125"},
+    };
+
+    for (const auto& entry : cases) {
+        Calculator function;
+
+        feed(function, entry[0]);
+        feed(function, entry[1]);
+        TEST_ASSERT_EQUAL_STRING(entry[2], function.getInput());
+    }
+}
+
+void test_calculator_function_accuracy(void) {
+    const char* const functions = "SOTLKERQI";
+    const double expected[] = {0.6018150, 0.7986355, 0.7535541, 3.6109179, 1.5682017, 1.1719142e16, 6.0827625, 1369, 0.0270270};
+
+    for (unsigned char i = 0; functions[i] != 0; i++) {
+        Calculator calc;
+        const char keys[] = {'3', '7', functions[i], 0};
+
+        feed(calc, keys);
+
+        // the input keeps CALC_PRECISION decimals, big values are good to
+        // about the 6 digits of the ATmega's 32 bit double
+        const double within = fmax(fabs(expected[i]) * 1e-5, 0.00005);
+
+        TEST_ASSERT_FLOAT_WITHIN(within, expected[i], atof(calc.getInput()));
+    }
+}
+
+void test_calculator_function_radians(void) {
+    Calculator calc;
+
+    calc.onLongPress('0');
+    feed(calc, "0.5236S");
+
+    TEST_ASSERT_FLOAT_WITHIN(1e-4, 0.5, atof(calc.getInput()));
+
+    // and back to degrees
+    Calculator degrees;
+
+    degrees.onLongPress('0');
+    degrees.onLongPress('0');
+    feed(degrees, "90S");
+
+    TEST_ASSERT_EQUAL_STRING("1", degrees.getInput());
+}
+
+void test_calculator_function_once_per_hold(void) {
+    Calculator calc;
+
+    feed(calc, "16");
+    // square root on 1, held through three long presses
+    hold(calc, 4, 0, 3);
+
+    TEST_ASSERT_EQUAL_STRING("4", calc.getInput());
+}
+
+void test_calculator_rollover(void) {
+    Calculator calc;
+
+    // 7 goes down, 8 goes down, 7 comes up, 8 comes up
+    calc.onPress(2, 0);
+    calc.onPress(2, 1);
+    calc.onRelease(2, 0);
+    calc.onRelease(2, 1);
+
+    TEST_ASSERT_EQUAL_STRING("78", calc.getInput());
+
+    Calculator reversed;
+
+    reversed.onPress(2, 0);
+    reversed.onPress(2, 1);
+    reversed.onRelease(2, 1);
+    reversed.onRelease(2, 0);
+
+    TEST_ASSERT_EQUAL_STRING("78", reversed.getInput());
+}
+
+void test_calculator_rollover_operator(void) {
+    Calculator calc;
+
+    // 1 + 2 = with each key still down when the next one goes
+    calc.onPress(4, 0);
+    calc.onPress(2, 3);
+    calc.onRelease(4, 0);
+    calc.onPress(4, 1);
+    calc.onRelease(2, 3);
+    calc.onRelease(4, 1);
+    tap(calc, 4, 3);
+
+    TEST_ASSERT_EQUAL_STRING("3", calc.getResult());
+}
+
+// a number that fits the display, as getInput and getResult have to give.
+// A sign or point on its own is a number still being typed.
+static bool isShownNumber(const char* value) {
+    if (memchr(value, 0, CALC_VALUE_SIZE + 1) == nullptr) {
+        return false;
+    }
+
+    const char* digits = value[0] == '-' ? value + 1 : value;
+
+    if (strcmp(digits, "") == 0 || strcmp(digits, ".") == 0) {
+        return true;
+    }
+
+    char* end;
+    const double number = strtod(value, &end);
+
+    return end != value && *end == 0 && !isnan(number) && !isinf(number);
+}
+
+void test_calculator_random_keys(void) {
+    // every key of the key map and what its chords and long presses give,
+    // but the memory long presses, which would wear out the EEPROM
+    const char keys[] = "0123456789.+-x/\nCabcdNBZ%GSOTLKERQIm\\";
+    const char longPresses[] = "0123456789+-x/";
+
+    randomSeed(3217);
+
+    for (unsigned char sequence = 0; sequence < 100; sequence++) {
+        Calculator calc;
+
+        for (unsigned char i = 0; i < 40; i++) {
+            if (random(4) == 0) {
+                calc.onLongPress(longPresses[random(sizeof(longPresses) - 1)]);
+            } else {
+                calc.onPress(keys[random(sizeof(keys) - 1)]);
+            }
+
+            TEST_ASSERT_TRUE(isShownNumber(calc.getInput()));
+            TEST_ASSERT_TRUE(calc.hasError() || isShownNumber(calc.getResult()));
+        }
+    }
+}
+
+void test_format_double_rounding(void) {
+    char buffer[CALC_VALUE_SIZE + 1];
+
+    TEST_ASSERT_EQUAL_STRING("1.2346", format_double(buffer, sizeof(buffer), 1.23456, 4));
+    TEST_ASSERT_EQUAL_STRING("0.5", format_double(buffer, sizeof(buffer), 0.5, 4));
+    TEST_ASSERT_EQUAL_STRING("-2.25", format_double(buffer, sizeof(buffer), -2.25, 2));
+    TEST_ASSERT_EQUAL_STRING("3", format_double(buffer, sizeof(buffer), 2.9999, 2));
+    TEST_ASSERT_EQUAL_STRING("0", format_double(buffer, sizeof(buffer), 0, 4));
+}
+
+void test_format_group(void) {
+    char buffer[CALC_SHOWN_SIZE];
+
+    strcpy(buffer, "1234567.5");
+    TEST_ASSERT_EQUAL_STRING("1,234,567.5", format_group(buffer, sizeof(buffer), ','));
+
+    strcpy(buffer, "-1234");
+    TEST_ASSERT_EQUAL_STRING("-1 234", format_group(buffer, sizeof(buffer), ' '));
+
+    strcpy(buffer, "999");
+    TEST_ASSERT_EQUAL_STRING("999", format_group(buffer, sizeof(buffer), ','));
 
-    // TEST_ASSERT_EQUAL_CHAR_ARRAY("1234", calc.getInput(), 5);
+    strcpy(buffer, "0.12345");
+    TEST_ASSERT_EQUAL_STRING("0.12345", format_group(buffer, sizeof(buffer), ','));
 }
 
 void setup() {
@@ -35,6 +342,22 @@ void setup() {
     digitalWrite(LED_BUILTIN, LOW);
     UNITY_BEGIN();
     RUN_TEST(test_calculator_numeric_input);
+    RUN_TEST(test_calculator_percent);
+    RUN_TEST(test_calculator_percent_long_press);
+    RUN_TEST(test_calculator_negate);
+    RUN_TEST(test_calculator_repeat_equals);
+    RUN_TEST(test_calculator_undo);
+    RUN_TEST(test_calculator_error_latch);
+    RUN_TEST(test_calculator_integer_division);
+    RUN_TEST(test_calculator_functions);
+    RUN_TEST(test_calculator_function_accuracy);
+    RUN_TEST(test_calculator_function_radians);
+    RUN_TEST(test_calculator_function_once_per_hold);
+    RUN_TEST(test_calculator_rollover);
+    RUN_TEST(test_calculator_rollover_operator);
+    RUN_TEST(test_calculator_random_keys);
+    RUN_TEST(test_format_double_rounding);
+    RUN_TEST(test_format_group);
     UNITY_END();
     digitalWrite(LED_BUILTIN, HIGH);
 }